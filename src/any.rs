@@ -0,0 +1,137 @@
+//! An object-safe trait to inspect and edit bounded values without knowing their concrete types, for example in admin interfaces or configuration dumps.
+
+/// The error that is returned when `AnyBounded::try_set_i128` is passed a value that isn't allowed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AnyOutOfBoundsError {
+    pub(crate) value: i128,
+    pub(crate) type_name: &'static str,
+}
+
+impl AnyOutOfBoundsError {
+    /// Returns the value that was attempted to be set.
+    #[must_use]
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// Returns the name of the bounded type, including its bounds.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl std::fmt::Display for AnyOutOfBoundsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value {} is not allowed for {}", self.value, self.type_name)
+    }
+}
+
+impl std::error::Error for AnyOutOfBoundsError {}
+
+/// A bounded integer of any type, with any bounds. This trait is object safe, so values of different bounded types can be collected as `dyn AnyBounded`.
+///
+/// All values are passed as `i128`. Methods that return a value return `None` if it doesn't fit, which is only possible for `u128` backing integers.
+/// ```
+/// use bounded_types::{AnyBounded, AtLeastU16, BoundedI64, StrictBoundedU8};
+/// use std::convert::TryFrom;
+///
+/// let mut fields: Vec<Box<dyn AnyBounded>> = vec![
+///     Box::new(BoundedI64::<-10, 10>::from(3)),
+///     Box::new(StrictBoundedU8::<1, 5>::try_from(5).unwrap()),
+///     Box::new(AtLeastU16::<1024>::from(8080)),
+/// ];
+/// assert_eq!(fields[0].max_i128(), Some(10));
+/// assert!(fields[1].try_set_i128(6).is_err());
+/// fields[2].try_set_i128(1024).unwrap();
+/// assert_eq!(fields[2].value_i128(), Some(1024));
+/// ```
+pub trait AnyBounded {
+    /// Returns the smallest allowed value.
+    fn min_i128(&self) -> Option<i128>;
+
+    /// Returns the largest allowed value.
+    fn max_i128(&self) -> Option<i128>;
+
+    /// Returns the stored value, even if it is out of bounds.
+    fn value_i128(&self) -> Option<i128>;
+
+    /// Returns whether the stored value is allowed.
+    fn is_valid(&self) -> bool;
+
+    /// Returns the name of the bounded type, including its bounds.
+    fn type_name(&self) -> &'static str;
+
+    /// Replaces the stored value.
+    /// # Errors
+    /// Fails if the value isn't allowed, in which case the stored value is kept.
+    fn try_set_i128(&mut self, value: i128) -> Result<(), AnyOutOfBoundsError>;
+}
+
+/// Implements `AnyBounded` for the bounded types of a backing integer.
+macro_rules! derive_any_bounded {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        paste::paste! {
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound] $type<MIN, MAX>, $int;
+            Some(MIN), Some(MAX), |this| this.unchecked(), |this| this.is_ok(), |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound] [<Strict $type>]<MIN, MAX>, $int;
+            Some(MIN), Some(MAX), |this| this.get(), |_| true, |value| Self::try_from(value).ok());
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound] [<HalfOpen $type>]<MIN, MAX>, $int;
+            Some(MIN), MAX.checked_sub(1), |this| this.unchecked(), |this| this.is_ok(), |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound] [<Exclusive $type>]<MIN, MAX>, $int;
+            MIN.checked_add(1), MAX.checked_sub(1), |this| this.unchecked(), |this| this.is_ok(), |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound] [<StrictHalfOpen $type>]<MIN, MAX>, $int;
+            Some(MIN), MAX.checked_sub(1), |this| this.get(), |_| true, |value| Self::try_from(value).ok());
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound] [<StrictExclusive $type>]<MIN, MAX>, $int;
+            MIN.checked_add(1), MAX.checked_sub(1), |this| this.get(), |_| true, |value| Self::try_from(value).ok());
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound, const STEP: $bound] [<Stepped $type>]<MIN, MAX, STEP>, $int;
+            Some(MIN), Self::values().next_back().map(|last| last.unchecked()), |this| this.unchecked(), |this| this.is_ok(),
+            |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+        derive_any_bounded!(@impl [const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound] [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>, $int;
+            Some(MIN), Some(MAX), |this| this.unchecked(), |this| this.is_ok(), |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+        derive_any_bounded!(@impl [D: crate::Domain<$int>] [<Union $type>]<D>, $int;
+            Self::sub_ranges().map(|range| *range.start()).min(), Self::sub_ranges().map(|range| *range.end()).max(),
+            |this| this.unchecked(), |this| this.is_ok(), |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+        }
+    };
+    ( $type: ident, $bound:ty, $int:ty; $at_least: ident, $at_most: ident ) => {
+        derive_any_bounded!(@impl [const MIN: $bound] $at_least<MIN>, $int;
+            Some(MIN), Some(<$int>::MAX), |this| this.unchecked(), |this| this.is_ok(), |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+        derive_any_bounded!(@impl [const MAX: $bound] $at_most<MAX>, $int;
+            Some(<$int>::MIN), Some(MAX), |this| this.unchecked(), |this| this.is_ok(), |value| Some(Self::from(value)).filter(|new| new.is_ok()));
+    };
+    ( @impl [ $( $generics: tt )* ] $name: ident < $( $args: tt ),* >, $int:ty; $min: expr, $max: expr, $value: expr, $valid: expr, $set: expr ) => {
+        impl< $( $generics )* > crate::AnyBounded for $name< $( $args ),* > {
+            #[allow(clippy::useless_conversion)]
+            fn min_i128(&self) -> Option<i128> {
+                $min.and_then(|min| i128::try_from(min).ok())
+            }
+
+            #[allow(clippy::useless_conversion)]
+            fn max_i128(&self) -> Option<i128> {
+                $max.and_then(|max| i128::try_from(max).ok())
+            }
+
+            #[allow(clippy::useless_conversion)]
+            fn value_i128(&self) -> Option<i128> {
+                let value: fn(&Self) -> $int = $value;
+                i128::try_from(value(self)).ok()
+            }
+
+            fn is_valid(&self) -> bool {
+                let valid: fn(&Self) -> bool = $valid;
+                valid(self)
+            }
+
+            fn type_name(&self) -> &'static str {
+                std::any::type_name::<Self>()
+            }
+
+            fn try_set_i128(&mut self, value: i128) -> Result<(), crate::AnyOutOfBoundsError> {
+                let set: fn(i128) -> Option<Self> = $set;
+                *self = set(value).ok_or(crate::AnyOutOfBoundsError { value, type_name: std::any::type_name::<Self>() })?;
+                Ok(())
+            }
+        }
+    };
+}
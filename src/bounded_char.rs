@@ -0,0 +1,68 @@
+//! Bounded `char` types, like `BoundedChar<'a', 'z'>`.
+
+use crate::BoundedU32;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+generate_bounded_value! {
+    char; parse: std::str::FromStr::from_str, std::char::ParseCharError;
+    [const MIN: char, const MAX: char] [MIN, MAX];
+    [const OTHER_MIN: char, const OTHER_MAX: char] [OTHER_MIN, OTHER_MAX];
+    bounds: MIN, MAX;
+    display: std::fmt::Display::fmt, std::fmt::Debug::fmt;
+    normalize: std::convert::identity;
+    /// The error that is returned when you attempt to assign an out-of-bounds value to a `BoundedChar`.
+    CharOutOfBoundsError;
+    /// A `char` element that is forced to be within the inclusive range `MIN..=MAX`.
+    #[derive(Serialize, Deserialize)]
+    BoundedChar;
+    /// A `char` element that is guaranteed to be within the inclusive range `MIN..=MAX`. In contrast to `BoundedChar`, it can't hold out-of-bounds values, so it is `Eq`, `Ord` and `Hash`.
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+    StrictBoundedChar;
+}
+
+impl<const MIN: char, const MAX: char> BoundedChar<MIN, MAX> {
+    /// Returns an iterator over all characters in `MIN..=MAX`, in ascending order.
+    pub fn values() -> impl DoubleEndedIterator<Item = Self> {
+        StrictBoundedChar::<MIN, MAX>::values().map(Self::from)
+    }
+}
+
+/// Converts the character into its code point. Out-of-bounds characters are converted as well, and the code point is checked against the new bounds.
+impl<const MIN: char, const MAX: char, const OTHER_MIN: u32, const OTHER_MAX: u32> From<BoundedChar<MIN, MAX>>
+    for BoundedU32<OTHER_MIN, OTHER_MAX>
+{
+    fn from(other: BoundedChar<MIN, MAX>) -> Self {
+        Self::from(u32::from(other.unchecked()))
+    }
+}
+
+impl<const MIN: char, const MAX: char> StrictBoundedChar<MIN, MAX> {
+    /// Returns an iterator over all characters in `MIN..=MAX`, in ascending order. Surrogate code points are skipped, since they aren't characters.
+    pub fn values() -> impl DoubleEndedIterator<Item = Self> {
+        (MIN..=MAX).map(Self)
+    }
+}
+
+/// Converts the character into its code point, which is checked against the new bounds.
+impl<const MIN: char, const MAX: char, const OTHER_MIN: u32, const OTHER_MAX: u32> From<StrictBoundedChar<MIN, MAX>>
+    for BoundedU32<OTHER_MIN, OTHER_MAX>
+{
+    fn from(other: StrictBoundedChar<MIN, MAX>) -> Self {
+        Self::from(u32::from(other.0))
+    }
+}
+
+impl<const MIN: char, const MAX: char> Serialize for StrictBoundedChar<MIN, MAX> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Out-of-bounds values are rejected while deserializing.
+impl<'de, const MIN: char, const MAX: char> Deserialize<'de> for StrictBoundedChar<MIN, MAX> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let val = char::deserialize(deserializer)?;
+        Self::try_from(val).map_err(serde::de::Error::custom)
+    }
+}
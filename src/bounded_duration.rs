@@ -0,0 +1,177 @@
+//! Bounded durations, like `BoundedDuration<100, 5000>` for a timeout between 100 milliseconds and 5 seconds.
+//!
+//! Durations are parsed from and displayed as an integer with a unit, like `"250ms"` or `"5s"`. The supported units are `ns`, `us` (or `µs`), `ms`, `s`, `m` and `h`.
+//! Both `BoundedDuration` and `StrictBoundedDuration` are serialized in the same format, so they fit into configuration files.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The error that is returned when a string can't be parsed as a duration.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParseDurationError {
+    /// The string doesn't start with an unsigned integer.
+    InvalidNumber,
+    /// The integer isn't followed by a supported unit.
+    UnknownUnit,
+    /// The duration is too large to be represented.
+    Overflow,
+}
+
+impl std::fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDurationError::InvalidNumber => write!(f, "the duration doesn't start with an unsigned integer"),
+            ParseDurationError::UnknownUnit => write!(f, "the duration has no valid unit, expected ns, us, ms, s, m or h"),
+            ParseDurationError::Overflow => write!(f, "the duration is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Parses an integer followed by a unit, like `"250ms"`.
+fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let s = s.trim();
+    let unit_start = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(unit_start);
+    let number = u64::from_str(number).map_err(|_| ParseDurationError::InvalidNumber)?;
+    let to_secs = |factor: u64| number.checked_mul(factor).map(Duration::from_secs).ok_or(ParseDurationError::Overflow);
+    match unit.trim_start() {
+        "ns" => Ok(Duration::from_nanos(number)),
+        "us" | "µs" => Ok(Duration::from_micros(number)),
+        "ms" => Ok(Duration::from_millis(number)),
+        "s" => Ok(Duration::from_secs(number)),
+        "m" => to_secs(60),
+        "h" => to_secs(60 * 60),
+        _ => Err(ParseDurationError::UnknownUnit),
+    }
+}
+
+/// Formats a duration with the largest unit that represents it exactly, so that it can be parsed again.
+fn format_duration(duration: &Duration, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let nanos = duration.as_nanos();
+    if nanos % 1_000_000_000 == 0 {
+        write!(f, "{}s", duration.as_secs())
+    } else if nanos % 1_000_000 == 0 {
+        write!(f, "{}ms", duration.as_millis())
+    } else if nanos % 1_000 == 0 {
+        write!(f, "{}us", duration.as_micros())
+    } else {
+        write!(f, "{nanos}ns")
+    }
+}
+
+/// Displays a duration like `format_duration`, for serializing it with `collect_str`.
+struct FormattedDuration(Duration);
+
+impl std::fmt::Display for FormattedDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        format_duration(&self.0, f)
+    }
+}
+
+generate_bounded_value! {
+    Duration; parse: parse_duration, ParseDurationError;
+    [const MIN_MS: u64, const MAX_MS: u64] [MIN_MS, MAX_MS];
+    [const OTHER_MIN_MS: u64, const OTHER_MAX_MS: u64] [OTHER_MIN_MS, OTHER_MAX_MS];
+    bounds: BoundedDuration::<MIN_MS, MAX_MS>::MIN, BoundedDuration::<MIN_MS, MAX_MS>::MAX;
+    display: format_duration, format_duration;
+    normalize: std::convert::identity;
+    /// The error that is returned when you attempt to assign an out-of-bounds value to a `BoundedDuration`.
+    DurationOutOfBoundsError;
+    /// A `Duration` that is forced to be within the inclusive range `MIN_MS..=MAX_MS` milliseconds. The duration itself has nanosecond precision.
+    ///
+    /// It is serialized as a string like `"250ms"`, even if it is out of bounds.
+    BoundedDuration;
+    /// A `Duration` that is guaranteed to be within the inclusive range `MIN_MS..=MAX_MS` milliseconds. In contrast to `BoundedDuration`, it can't hold out-of-bounds values, so it is `Eq`, `Ord` and `Hash`.
+    ///
+    /// It is serialized as a string like `"250ms"`.
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+    StrictBoundedDuration;
+}
+
+impl<const MIN_MS: u64, const MAX_MS: u64> BoundedDuration<MIN_MS, MAX_MS> {
+    /// The smallest allowed value.
+    pub const MIN: Duration = Duration::from_millis(MIN_MS);
+
+    /// The largest allowed value.
+    pub const MAX: Duration = Duration::from_millis(MAX_MS);
+
+    /// Converts a duration into a bounded value. Durations outside of the bounds are clamped to `MIN` or `MAX`.
+    #[must_use]
+    pub fn from_duration_clamped(val: Duration) -> Self {
+        // unlike `clamp`, this doesn't panic if the bounds are empty, and produces an out-of-bounds value instead
+        Self::from(val.max(Self::MIN).min(Self::MAX))
+    }
+
+    /// Adds a duration, saturating at `MAX`. If `self` is out of bounds, the result is out of bounds as well.
+    #[must_use]
+    pub fn saturating_add(self, other: Duration) -> Self {
+        match self.0 {
+            Ok(val) => Self::from(val.saturating_add(other).min(Self::MAX)),
+            Err(err) => Self::from(err.value().saturating_add(other)),
+        }
+    }
+
+    /// Subtracts a duration, saturating at `MIN`. If `self` is out of bounds, the result is out of bounds as well.
+    #[must_use]
+    pub fn saturating_sub(self, other: Duration) -> Self {
+        match self.0 {
+            Ok(val) => Self::from(val.saturating_sub(other).max(Self::MIN)),
+            Err(err) => Self::from(err.value().saturating_sub(other)),
+        }
+    }
+}
+
+impl<const MIN_MS: u64, const MAX_MS: u64> Serialize for BoundedDuration<MIN_MS, MAX_MS> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&FormattedDuration(self.unchecked()))
+    }
+}
+
+/// Invalid strings are rejected while deserializing, out-of-bounds values are kept as such.
+impl<'de, const MIN_MS: u64, const MAX_MS: u64> Deserialize<'de> for BoundedDuration<MIN_MS, MAX_MS> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let val = Cow::<'de, str>::deserialize(deserializer)?;
+        Self::from_str(&val).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<const MIN_MS: u64, const MAX_MS: u64> StrictBoundedDuration<MIN_MS, MAX_MS> {
+    /// Converts a duration into a strict value. Durations outside of the bounds are clamped to `MIN` or `MAX`. Only compiles if the bounds aren't empty.
+    #[must_use]
+    pub fn from_duration_clamped(val: Duration) -> Self {
+        const { assert!(MIN_MS <= MAX_MS, "the bounds are empty, so there is no value to clamp to") };
+        Self(val.max(BoundedDuration::<MIN_MS, MAX_MS>::MIN).min(BoundedDuration::<MIN_MS, MAX_MS>::MAX))
+    }
+
+    /// Adds a duration, saturating at `MAX`.
+    #[must_use]
+    pub fn saturating_add(self, other: Duration) -> Self {
+        Self::from_duration_clamped(self.0.saturating_add(other))
+    }
+
+    /// Subtracts a duration, saturating at `MIN`.
+    #[must_use]
+    pub fn saturating_sub(self, other: Duration) -> Self {
+        Self::from_duration_clamped(self.0.saturating_sub(other))
+    }
+}
+
+impl<const MIN_MS: u64, const MAX_MS: u64> Serialize for StrictBoundedDuration<MIN_MS, MAX_MS> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Invalid strings and out-of-bounds values are rejected while deserializing.
+impl<'de, const MIN_MS: u64, const MAX_MS: u64> Deserialize<'de> for StrictBoundedDuration<MIN_MS, MAX_MS> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let val = Cow::<'de, str>::deserialize(deserializer)?;
+        let duration = parse_duration(&val).map_err(serde::de::Error::custom)?;
+        Self::try_from(duration).map_err(serde::de::Error::custom)
+    }
+}
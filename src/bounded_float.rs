@@ -0,0 +1,102 @@
+//! Bounded floating-point types.
+//!
+//! Const generics can't take `f64`, so the bounds are given as fractions with a shared denominator: `BoundedF64<NUM_MIN, NUM_MAX, DEN>` covers `NUM_MIN / DEN..=NUM_MAX / DEN`.
+//! NaN is never in bounds.
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+
+generate_bounded_value! {
+    f64; parse: std::str::FromStr::from_str, std::num::ParseFloatError;
+    [const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64] [NUM_MIN, NUM_MAX, DEN];
+    [const OTHER_NUM_MIN: i64, const OTHER_NUM_MAX: i64, const OTHER_DEN: u64] [OTHER_NUM_MIN, OTHER_NUM_MAX, OTHER_DEN];
+    bounds: BoundedF64::<NUM_MIN, NUM_MAX, DEN>::MIN, BoundedF64::<NUM_MIN, NUM_MAX, DEN>::MAX;
+    display: std::fmt::Display::fmt, std::fmt::Display::fmt;
+    // adding positive zero turns -0.0 into 0.0 and keeps every other value
+    normalize: |val| val + 0.0;
+    /// The error that is returned when you attempt to assign an out-of-bounds value (or NaN) to a `BoundedF64`.
+    FloatOutOfBoundsError;
+    /// An `f64` element that is forced to be within the inclusive range `NUM_MIN / DEN..=NUM_MAX / DEN`. NaN is always out of bounds.
+    ///
+    /// For example, `BoundedF64<0, 1, 1>` holds probabilities, `BoundedF64<-1, 1, 1>` holds gains and `BoundedF64<0, 100, 1>` holds percentages.
+    #[derive(Serialize, Deserialize)]
+    BoundedF64;
+    /// An `f64` element that is guaranteed to be within the inclusive range `NUM_MIN / DEN..=NUM_MAX / DEN`. In contrast to `BoundedF64`, it can't hold out-of-bounds values or NaN, so it is `Eq`, `Ord` and `Hash`.
+    ///
+    /// `-0.0` is stored as `0.0`, so equal values always have the same hash.
+    #[derive(PartialEq)]
+    StrictBoundedF64;
+}
+
+impl<const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> BoundedF64<NUM_MIN, NUM_MAX, DEN> {
+    /// The smallest allowed value, `NUM_MIN / DEN`. Only compiles if `DEN` isn't zero.
+    #[allow(clippy::cast_precision_loss)]
+    pub const MIN: f64 = {
+        assert!(DEN != 0, "the denominator must not be zero");
+        NUM_MIN as f64 / DEN as f64
+    };
+
+    /// The largest allowed value, `NUM_MAX / DEN`. Only compiles if `DEN` isn't zero.
+    #[allow(clippy::cast_precision_loss)]
+    pub const MAX: f64 = {
+        assert!(DEN != 0, "the denominator must not be zero");
+        NUM_MAX as f64 / DEN as f64
+    };
+
+    /// Converts a float into a bounded value. Values outside of the bounds (including infinities) are clamped to `MIN` or `MAX`.
+    /// Returns `None` if the float is NaN.
+    #[must_use]
+    pub fn from_f64_clamped(val: f64) -> Option<Self> {
+        if val.is_nan() {
+            None
+        } else {
+            Some(Self::from(val.max(Self::MIN).min(Self::MAX)))
+        }
+    }
+}
+
+impl<const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> StrictBoundedF64<NUM_MIN, NUM_MAX, DEN> {
+    /// Converts a float into a strict value. Values outside of the bounds (including infinities) are clamped to `MIN` or `MAX`.
+    /// Returns `None` if the float is NaN.
+    #[must_use]
+    pub fn from_f64_clamped(val: f64) -> Option<Self> {
+        BoundedF64::from_f64_clamped(val).and_then(|bounded| bounded.strict().ok())
+    }
+}
+
+impl<const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> Eq for StrictBoundedF64<NUM_MIN, NUM_MAX, DEN> {}
+
+impl<const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> PartialOrd for StrictBoundedF64<NUM_MIN, NUM_MAX, DEN> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> Ord for StrictBoundedF64<NUM_MIN, NUM_MAX, DEN> {
+    // without NaN and -0.0, the total order of floats matches their partial order
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> Hash for StrictBoundedF64<NUM_MIN, NUM_MAX, DEN> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl<const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> Serialize for StrictBoundedF64<NUM_MIN, NUM_MAX, DEN> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Out-of-bounds values and NaN are rejected while deserializing.
+impl<'de, const NUM_MIN: i64, const NUM_MAX: i64, const DEN: u64> Deserialize<'de> for StrictBoundedF64<NUM_MIN, NUM_MAX, DEN> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let val = f64::deserialize(deserializer)?;
+        Self::try_from(val).map_err(serde::de::Error::custom)
+    }
+}
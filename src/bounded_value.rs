@@ -0,0 +1,248 @@
+//! The skeleton shared by the bounded types of values that aren't integers, like floats, characters and durations.
+//!
+//! Their bounds can't always be given as const generics of the value type, so the macro takes the generic parameters and the expressions for the bounds separately.
+
+/// Generates the error type, the lenient type (wrapping a `Result`) and the strict type for a value type, along with their conversions and comparisons.
+///
+/// - `$parse` parses a value from a string, failing with `$parse_err`.
+/// - `$min` and `$max` are the bounds, evaluated with the generic parameters in scope.
+/// - `$display` formats valid values, `$error_display` formats the values in the error message.
+/// - `$normalize` is applied to values stored in the strict type, so that equal values have the same representation.
+///
+/// Everything that depends on the value type, like serialization of the strict type, is left to the caller.
+macro_rules! generate_bounded_value {
+    (
+        $val:ty; parse: $parse:expr, $parse_err:ty;
+        [ $( $generics:tt )* ] [ $( $args:ident ),* ];
+        [ $( $other_generics:tt )* ] [ $( $other_args:ident ),* ];
+        bounds: $min:expr, $max:expr;
+        display: $display:expr, $error_display:expr;
+        normalize: $normalize:expr;
+        $( #[$error_attr:meta] )* $error:ident;
+        $( #[$lenient_attr:meta] )* $lenient:ident;
+        $( #[$strict_attr:meta] )* $strict:ident;
+    ) => {
+        $( #[$error_attr] )*
+        #[derive(shrinkwraprs::Shrinkwrap, Copy, Clone)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct $error< $( $generics )* >($val);
+
+        // takes `&self` like the errors of the integer types
+        #[allow(clippy::trivially_copy_pass_by_ref)]
+        impl< $( $generics )* > $error< $( $args ),* > {
+            /// Returns the value that was attempted to be passed.
+            #[must_use]
+            pub fn value(&self) -> $val {
+                self.0
+            }
+
+            /// Returns the smallest allowed value.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn min_allowed(&self) -> $val {
+                $min
+            }
+
+            /// Returns the largest allowed value.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn max_allowed(&self) -> $val {
+                $max
+            }
+        }
+
+        impl< $( $generics )* > std::fmt::Debug for $error< $( $args ),* > {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt.debug_struct(stringify!($error))
+                    .field("value", &self.value())
+                    .field("min_allowed", &self.min_allowed())
+                    .field("max_allowed", &self.max_allowed())
+                    .finish()
+            }
+        }
+
+        impl< $( $generics )* > std::fmt::Display for $error< $( $args ),* > {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let display: fn(&$val, &mut std::fmt::Formatter<'_>) -> std::fmt::Result = $error_display;
+                write!(f, "value ")?;
+                display(&self.value(), f)?;
+                write!(f, " is out of bounds, allowed range is ")?;
+                display(&self.min_allowed(), f)?;
+                write!(f, "..=")?;
+                display(&self.max_allowed(), f)
+            }
+        }
+
+        impl< $( $generics )* > std::error::Error for $error< $( $args ),* > {}
+
+        $( #[$lenient_attr] )*
+        #[derive(shrinkwraprs::Shrinkwrap, Debug, Copy, Clone)]
+        pub struct $lenient< $( $generics )* >(Result<$val, $error< $( $args ),* >>);
+
+        impl< $( $generics )* > $lenient< $( $args ),* > {
+            /// Returns the value stored in the struct, but overrides the bounds check.
+            #[must_use]
+            pub fn unchecked(&self) -> $val {
+                match &self.0 {
+                    Ok(val) => *val,
+                    Err(err) => err.value(),
+                }
+            }
+
+            /// Function that returns whether a value is within the bounds.
+            #[must_use]
+            pub fn is_in_bounds(val: $val) -> bool {
+                ($min..=$max).contains(&val)
+            }
+
+            /// Converts the value into its strict variant.
+            /// # Errors
+            /// Fails if the value is out of bounds.
+            pub fn strict(self) -> Result<$strict< $( $args ),* >, $error< $( $args ),* >> {
+                let normalize: fn($val) -> $val = $normalize;
+                self.0.map(|val| $strict(normalize(val)))
+            }
+        }
+
+        impl< $( $generics )* > From<$val> for $lenient< $( $args ),* > {
+            fn from(other: $val) -> Self {
+                if Self::is_in_bounds(other) {
+                    Self(Ok(other))
+                } else {
+                    Self(Err($error(other)))
+                }
+            }
+        }
+
+        impl< $( $generics )* > std::fmt::Display for $lenient< $( $args ),* > {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let display: fn(&$val, &mut std::fmt::Formatter<'_>) -> std::fmt::Result = $display;
+                match &self.0 {
+                    Ok(val) => display(val, f),
+                    Err(err) => write!(f, "{err:?}"),
+                }
+            }
+        }
+
+        impl< $( $generics )* > std::str::FromStr for $lenient< $( $args ),* > {
+            type Err = $parse_err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parse: fn(&str) -> Result<$val, $parse_err> = $parse;
+                parse(s).map(Self::from)
+            }
+        }
+
+        // Note: the lenient type is not Eq because x != x for x.is_err()
+
+        impl< $( $generics )* > PartialEq<$val> for $lenient< $( $args ),* > {
+            // will throw false if self is out of bounds
+            fn eq(&self, other: &$val) -> bool {
+                match self.0 {
+                    Ok(val) => val == *other,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl< $( $generics )* > PartialOrd<$val> for $lenient< $( $args ),* > {
+            // will throw false if self is out of bounds
+            fn partial_cmp(&self, other: &$val) -> Option<std::cmp::Ordering> {
+                self.0.ok()?.partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl< $( $generics )* > PartialEq<$lenient< $( $args ),* >> for $val {
+            fn eq(&self, other: &$lenient< $( $args ),* >) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl< $( $generics )* > PartialOrd<$lenient< $( $args ),* >> for $val {
+            fn partial_cmp(&self, other: &$lenient< $( $args ),* >) -> Option<std::cmp::Ordering> {
+                other.partial_cmp(self).map(std::cmp::Ordering::reverse)
+            }
+        }
+
+        impl< $( $generics )*, $( $other_generics )* > PartialEq<$lenient< $( $other_args ),* >> for $lenient< $( $args ),* > {
+            fn eq(&self, other: &$lenient< $( $other_args ),* >) -> bool {
+                match other.0 {
+                    Ok(other_val) => *self == other_val,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl< $( $generics )*, $( $other_generics )* > PartialOrd<$lenient< $( $other_args ),* >> for $lenient< $( $args ),* > {
+            fn partial_cmp(&self, other: &$lenient< $( $other_args ),* >) -> Option<std::cmp::Ordering> {
+                self.partial_cmp(&other.0.ok()?)
+            }
+        }
+
+        $( #[$strict_attr] )*
+        #[derive(shrinkwraprs::Shrinkwrap, Debug, Copy, Clone)]
+        pub struct $strict< $( $generics )* >($val);
+
+        impl< $( $generics )* > $strict< $( $args ),* > {
+            /// Returns the value stored in the struct.
+            #[must_use]
+            pub fn get(self) -> $val {
+                self.0
+            }
+        }
+
+        impl< $( $generics )* > std::convert::TryFrom<$val> for $strict< $( $args ),* > {
+            type Error = $error< $( $args ),* >;
+            fn try_from(other: $val) -> Result<Self, Self::Error> {
+                $lenient::from(other).strict()
+            }
+        }
+
+        impl< $( $generics )* > From<$strict< $( $args ),* >> for $lenient< $( $args ),* > {
+            fn from(other: $strict< $( $args ),* >) -> Self {
+                Self(Ok(other.0))
+            }
+        }
+
+        impl< $( $generics )* > std::convert::TryFrom<$lenient< $( $args ),* >> for $strict< $( $args ),* > {
+            type Error = $error< $( $args ),* >;
+            fn try_from(other: $lenient< $( $args ),* >) -> Result<Self, Self::Error> {
+                other.strict()
+            }
+        }
+
+        impl< $( $generics )* > std::fmt::Display for $strict< $( $args ),* > {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let display: fn(&$val, &mut std::fmt::Formatter<'_>) -> std::fmt::Result = $display;
+                display(&self.0, f)
+            }
+        }
+
+        impl< $( $generics )* > PartialEq<$val> for $strict< $( $args ),* > {
+            fn eq(&self, other: &$val) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl< $( $generics )* > PartialOrd<$val> for $strict< $( $args ),* > {
+            fn partial_cmp(&self, other: &$val) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl< $( $generics )* > PartialEq<$strict< $( $args ),* >> for $val {
+            fn eq(&self, other: &$strict< $( $args ),* >) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl< $( $generics )* > PartialOrd<$strict< $( $args ),* >> for $val {
+            fn partial_cmp(&self, other: &$strict< $( $args ),* >) -> Option<std::cmp::Ordering> {
+                other.partial_cmp(self).map(std::cmp::Ordering::reverse)
+            }
+        }
+    };
+}
@@ -0,0 +1,79 @@
+//! Support for parsing bounded values from command line arguments with `clap`.
+
+/// Generates a `clap` value parser for a bounded type, and registers it so that `value_parser!` and `#[arg]` pick it up automatically.
+macro_rules! derive_clap {
+    ( $type: ident, $bound:ty, $int:ty; $help: literal ) => {
+        paste::paste! {
+        #[cfg(feature = "clap")]
+        #[derive(Debug, Default, Copy, Clone)]
+        #[doc="Parses command line arguments into a `" $type "`. Out-of-bounds values are rejected with an error that names the range, and `" $type "::range_help` names it in `--help`."]
+        pub struct [<$type ValueParser>]<const MIN: $bound, const MAX: $bound>;
+
+        #[cfg(feature = "clap")]
+        impl<const MIN: $bound, const MAX: $bound> [<$type ValueParser>]<MIN, MAX> {
+            /// Creates a new parser.
+            #[must_use]
+            pub fn new() -> Self {
+                Self
+            }
+        }
+
+        #[cfg(feature = "clap")]
+        impl<const MIN: $bound, const MAX: $bound> $type<MIN, MAX> {
+            /// Describes the allowed range like the errors of the value parser do, for example `between 1 and 65535`.
+            /// Add it to the help of an argument, like `#[arg(help = format!("Port to listen on, {}", Port::range_help()))]`, to show the range in `--help`.
+            #[must_use]
+            pub fn range_help() -> String {
+                format!($help, MIN, MAX)
+            }
+        }
+
+        #[cfg(feature = "clap")]
+        impl<const MIN: $bound, const MAX: $bound> ::clap::builder::TypedValueParser for [<$type ValueParser>]<MIN, MAX> {
+            type Value = $type<MIN, MAX>;
+
+            fn parse_ref(
+                &self,
+                cmd: &::clap::Command,
+                arg: Option<&::clap::Arg>,
+                value: &std::ffi::OsStr,
+            ) -> Result<Self::Value, ::clap::Error> {
+                use std::num::IntErrorKind;
+
+                let invalid = |reason: &dyn std::fmt::Display| {
+                    let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+                    ::clap::Error::raw(
+                        ::clap::error::ErrorKind::ValueValidation,
+                        format!("invalid value '{}' for '{}': {}\n", value.to_string_lossy(), arg, reason),
+                    )
+                    .with_cmd(cmd)
+                };
+                let out_of_bounds = || invalid(&format_args!("must be {}", Self::Value::range_help()));
+
+                let raw = value.to_str().ok_or_else(|| invalid(&"invalid UTF-8"))?;
+                match <$int>::from_str(raw) {
+                    Ok(val) if Self::Value::is_in_bounds(&val) => Ok(Self::Value::from(val)),
+                    Ok(_) => Err(out_of_bounds()),
+                    // the argument is an integer, it just doesn't fit $int (this includes negative values for unsigned types)
+                    Err(err)
+                        if matches!(err.kind(), IntErrorKind::PosOverflow | IntErrorKind::NegOverflow)
+                            || raw.parse::<i128>().is_ok() =>
+                    {
+                        Err(out_of_bounds())
+                    }
+                    Err(err) => Err(invalid(&err)),
+                }
+            }
+        }
+
+        #[cfg(feature = "clap")]
+        impl<const MIN: $bound, const MAX: $bound> ::clap::builder::ValueParserFactory for $type<MIN, MAX> {
+            type Parser = [<$type ValueParser>]<MIN, MAX>;
+
+            fn value_parser() -> Self::Parser {
+                [<$type ValueParser>]
+            }
+        }
+        }
+    };
+}
@@ -0,0 +1,5 @@
+use bounded_types::*;
+
+fn main() {
+    let _port = DefaultedBoundedU16::<1024, 65535, 80>::default();
+}
@@ -0,0 +1,5 @@
+use bounded_types::*;
+
+fn main() {
+    let _temperature = SoftBoundedI16::<-40, 85, 50, 20>::from(30);
+}
@@ -0,0 +1,7 @@
+use bounded_types::*;
+use std::convert::TryFrom;
+
+fn main() {
+    let strict = StrictBoundedI64::<-1, 255>::try_from(5).unwrap();
+    let _byte: u8 = strict.to_u8();
+}
@@ -0,0 +1,6 @@
+use bounded_types::*;
+
+fn main() {
+    let narrow: BoundedU16<1, 10> = 5.into();
+    let _wide: BoundedU16<2, 100> = narrow.widen();
+}
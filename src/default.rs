@@ -0,0 +1,161 @@
+//! `Default` for the bounded types, and the `Defaulted` wrappers with a custom default value.
+//!
+//! The default is the allowed value that is closest to zero, which is `MIN` for bounds that don't contain negative values.
+
+/// Returns the value within `min..=max` that is closest to zero. A missing bound isn't checked.
+pub(crate) fn closest_to_zero<T: PartialOrd + Default>(min: Option<T>, max: Option<T>) -> T {
+    match (min, max) {
+        (Some(min), _) if min > T::default() => min,
+        (_, Some(max)) if max < T::default() => max,
+        _ => T::default(),
+    }
+}
+
+/// Implements `Default` for the bounded types of a backing integer.
+macro_rules! derive_default {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        paste::paste! {
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound] $type<MIN, MAX>;
+            Self::from(crate::default::closest_to_zero(Some(MIN), Some(MAX))));
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound] [<Strict $type>]<MIN, MAX>; {
+            const { assert!(MIN <= MAX, "the bounds are empty, so there is no default value") };
+            Self(crate::default::closest_to_zero(Some(MIN), Some(MAX)))
+        });
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound] [<HalfOpen $type>]<MIN, MAX>;
+            Self::from(crate::default::closest_to_zero(Some(MIN), MAX.checked_sub(1))));
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound] [<Exclusive $type>]<MIN, MAX>;
+            Self::from(crate::default::closest_to_zero(MIN.checked_add(1), MAX.checked_sub(1))));
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound] [<StrictHalfOpen $type>]<MIN, MAX>; {
+            const { assert!([<HalfOpen $type>]::<MIN, MAX>::allowed_range().is_some(), "the bounds are empty, so there is no default value") };
+            Self(crate::default::closest_to_zero(Some(MIN), MAX.checked_sub(1)))
+        });
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound] [<StrictExclusive $type>]<MIN, MAX>; {
+            const { assert!([<Exclusive $type>]::<MIN, MAX>::allowed_range().is_some(), "the bounds are empty, so there is no default value") };
+            Self(crate::default::closest_to_zero(MIN.checked_add(1), MAX.checked_sub(1)))
+        });
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound, const STEP: $bound] [<Stepped $type>]<MIN, MAX, STEP>;
+            Self::round_to_step(0));
+        derive_default!(@impl [const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound] [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>;
+            Self::from(crate::default::closest_to_zero(Some(MIN), Some(MAX))));
+        // ties are broken towards the smaller value
+        derive_default!(@impl [D: crate::Domain<$int>] [<Union $type>]<D>;
+            Self::sub_ranges()
+                .map(|range| crate::default::closest_to_zero(Some(*range.start()), Some(*range.end())))
+                .min_by_key(|val: &$int| (val.abs_diff(0), *val))
+                .map_or(Self::from(0), Self::from));
+        }
+    };
+    ( $type: ident, $bound:ty, $int:ty; $at_least: ident, $at_most: ident ) => {
+        derive_default!(@impl [const MIN: $bound] $at_least<MIN>; Self::from(crate::default::closest_to_zero(Some(MIN), None)));
+        derive_default!(@impl [const MAX: $bound] $at_most<MAX>; Self::from(crate::default::closest_to_zero(None, Some(MAX))));
+    };
+    ( @impl [ $( $generics: tt )* ] $name: ident < $( $args: tt ),* >; $default: expr ) => {
+        /// Returns the allowed value that is closest to zero.
+        impl< $( $generics )* > Default for $name< $( $args ),* > {
+            fn default() -> Self {
+                $default
+            }
+        }
+    };
+}
+
+/// Generates the wrapper of a bounded type with a custom default value. It is named like the bounded type with a `Defaulted` prefix.
+macro_rules! generate_defaulted {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        paste::paste! {
+        #[derive(Shrinkwrap, Debug, Copy, Clone)]
+        #[shrinkwrap(mutable)]
+        #[derive(Serialize, Deserialize)]
+        #[serde(transparent)]
+        #[doc="A `" $type "<MIN, MAX>` whose `Default` is `DEFAULT`. Calling `default` only compiles if `DEFAULT` is within `MIN..=MAX`."]
+        pub struct [<Defaulted $type>]<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound>(pub $type<MIN, MAX>);
+
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> [<Defaulted $type>]<MIN, MAX, DEFAULT> {
+            /// Returns the wrapped bounded value.
+            #[must_use]
+            pub fn into_inner(self) -> $type<MIN, MAX> {
+                self.0
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> Default for [<Defaulted $type>]<MIN, MAX, DEFAULT> {
+            fn default() -> Self {
+                const { assert!(MIN <= DEFAULT && DEFAULT <= MAX, "the default value has to be within the bounds") };
+                Self($type::from(DEFAULT))
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> std::fmt::Display for [<Defaulted $type>]<MIN, MAX, DEFAULT> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> FromStr for [<Defaulted $type>]<MIN, MAX, DEFAULT> {
+            type Err = <$bound as FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $type::from_str(s).map(Self)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> From<$type<MIN, MAX>> for [<Defaulted $type>]<MIN, MAX, DEFAULT> {
+            fn from(other: $type<MIN, MAX>) -> Self {
+                Self(other)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> From<[<Defaulted $type>]<MIN, MAX, DEFAULT>> for $type<MIN, MAX> {
+            fn from(other: [<Defaulted $type>]<MIN, MAX, DEFAULT>) -> Self {
+                other.0
+            }
+        }
+
+        impl<T, const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> PartialEq<T> for [<Defaulted $type>]<MIN, MAX, DEFAULT>
+        where
+            $type<MIN, MAX>: PartialEq<T>,
+        {
+            fn eq(&self, other: &T) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<T, const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> PartialOrd<T> for [<Defaulted $type>]<MIN, MAX, DEFAULT>
+        where
+            $type<MIN, MAX>: PartialOrd<T>,
+        {
+            fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        derive_defaulted_numeric_traits!([<Defaulted $type>], $type, $bound; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        }
+    };
+}
+
+/// Derives the conversions from other numeric types into a `Defaulted` type, and the reverse comparisons.
+macro_rules! derive_defaulted_numeric_traits {
+    ( $defaulted: ident, $type: ident, $bound:ty; $( $numeric:ty ),* ) => {
+        $(
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> From<$numeric> for $defaulted<MIN, MAX, DEFAULT> {
+            fn from(other: $numeric) -> Self {
+                Self($type::from(other))
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> PartialEq<$defaulted<MIN, MAX, DEFAULT>> for $numeric {
+            fn eq(&self, other: &$defaulted<MIN, MAX, DEFAULT>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const MIN: $bound, const MAX: $bound, const DEFAULT: $bound> PartialOrd<$defaulted<MIN, MAX, DEFAULT>> for $numeric {
+            fn partial_cmp(&self, other: &$defaulted<MIN, MAX, DEFAULT>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )*
+    };
+}
@@ -0,0 +1,234 @@
+//! Bounded values whose bounds are only known at runtime, for example because they are read from a configuration file.
+
+use serde::de::{DeserializeSeed, Deserializer, Error as _};
+use serde::{Deserialize, Serialize, Serializer};
+use shrinkwraprs::Shrinkwrap;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+
+/// The error that is returned when you attempt to assign an out-of-bounds value to a `DynBounded`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DynOutOfBoundsError<T> {
+    value: T,
+    min: T,
+    max: T,
+}
+
+impl<T: Copy> DynOutOfBoundsError<T> {
+    /// Returns the value that was attempted to be passed.
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the smallest allowed value.
+    #[must_use]
+    pub fn min_allowed(&self) -> T {
+        self.min
+    }
+
+    /// Returns the largest allowed value.
+    #[must_use]
+    pub fn max_allowed(&self) -> T {
+        self.max
+    }
+}
+
+impl<T: Display> Display for DynOutOfBoundsError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value {} is out of bounds, allowed range is {}..={}", self.value, self.min, self.max)
+    }
+}
+
+impl<T: Debug + Display> std::error::Error for DynOutOfBoundsError<T> {}
+
+/// The inclusive bounds of a `DynBounded`, known at runtime.
+///
+/// It is also a `DeserializeSeed`, so values can be deserialized with bounds from a configuration file. Out-of-bounds values are rejected while deserializing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DynBounds<T> {
+    min: T,
+    max: T,
+}
+
+impl<T: PartialOrd + Copy> DynBounds<T> {
+    /// Creates the bounds `min..=max`.
+    #[must_use]
+    pub fn new(min: T, max: T) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the smallest allowed value.
+    #[must_use]
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Returns the largest allowed value.
+    #[must_use]
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Function that returns whether a value is within the bounds.
+    pub fn is_in_bounds(&self, val: &impl PartialOrd<T>) -> bool {
+        *val >= self.min && *val <= self.max
+    }
+
+    /// Checks a value against the bounds.
+    #[must_use]
+    pub fn bound(self, value: T) -> DynBounded<T> {
+        DynBounded::new(value, self.min, self.max)
+    }
+}
+
+impl<'de, T: PartialOrd + Copy + Display + Deserialize<'de>> DeserializeSeed<'de> for DynBounds<T> {
+    type Value = DynBounded<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let bounded = self.bound(T::deserialize(deserializer)?);
+        match bounded.value {
+            Ok(_) => Ok(bounded),
+            Err(err) => Err(D::Error::custom(err)),
+        }
+    }
+}
+
+/// An element that is forced to be within the inclusive range `min..=max`, where the bounds are only known at runtime.
+///
+/// It behaves like the const-bounded types: out-of-bounds values are stored as errors, and comparisons with them always return `false`.
+#[derive(Shrinkwrap, Debug, Copy, Clone)]
+pub struct DynBounded<T> {
+    #[shrinkwrap(main_field)]
+    value: Result<T, DynOutOfBoundsError<T>>,
+    bounds: DynBounds<T>,
+}
+
+impl<T: PartialOrd + Copy> DynBounded<T> {
+    /// Checks `value` against the bounds `min..=max`.
+    #[must_use]
+    pub fn new(value: T, min: T, max: T) -> Self {
+        let bounds = DynBounds::new(min, max);
+        let value = if bounds.is_in_bounds(&value) {
+            Ok(value)
+        } else {
+            Err(DynOutOfBoundsError { value, min, max })
+        };
+        Self { value, bounds }
+    }
+
+    /// Returns the value stored in the struct, but overrides the bounds check.
+    #[must_use]
+    pub fn unchecked(&self) -> T {
+        match &self.value {
+            Ok(val) => *val,
+            Err(err) => err.value(),
+        }
+    }
+
+    /// Returns the bounds.
+    #[must_use]
+    pub fn bounds(&self) -> DynBounds<T> {
+        self.bounds
+    }
+
+    /// Function that returns whether a value is within the bounds of `self`.
+    pub fn is_in_bounds(&self, val: &impl PartialOrd<T>) -> bool {
+        self.bounds.is_in_bounds(val)
+    }
+
+    /// Replaces the value, keeping the bounds.
+    #[must_use]
+    pub fn with_value(self, value: T) -> Self {
+        self.bounds.bound(value)
+    }
+}
+
+impl<T: Display> Display for DynBounded<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Ok(val) => write!(f, "{val}"),
+            Err(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl<T: PartialOrd> PartialEq<T> for DynBounded<T> {
+    // will throw false if self is out of bounds
+    fn eq(&self, other: &T) -> bool {
+        match &self.value {
+            Ok(val) => val == other,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<T> for DynBounded<T> {
+    // will throw false if self is out of bounds
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.value.as_ref().ok()?.partial_cmp(other)
+    }
+}
+
+impl<T: PartialOrd> PartialEq for DynBounded<T> {
+    // will throw false if either value is out of bounds, regardless of the bounds
+    fn eq(&self, other: &Self) -> bool {
+        match &other.value {
+            Ok(other_val) => self == other_val,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for DynBounded<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.partial_cmp(other.value.as_ref().ok()?)
+    }
+}
+
+/// Out-of-bounds values can't be serialized. The bounds aren't serialized.
+impl<T: Serialize + Display> Serialize for DynBounded<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.value {
+            Ok(val) => val.serialize(serializer),
+            Err(err) => Err(serde::ser::Error::custom(err)),
+        }
+    }
+}
+
+/// Derives the conversions between a const-bounded type and `DynBounded`, and the comparisons of its backing integer with `DynBounded`.
+macro_rules! derive_dyn_bounded {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        impl<const MIN: $bound, const MAX: $bound> From<$type<MIN, MAX>> for crate::DynBounded<$int> {
+            fn from(other: $type<MIN, MAX>) -> Self {
+                crate::DynBounded::new(other.unchecked(), MIN, MAX)
+            }
+        }
+
+        /// Only succeeds if the runtime bounds are exactly `MIN..=MAX`, otherwise the value is returned unchanged.
+        impl<const MIN: $bound, const MAX: $bound> TryFrom<crate::DynBounded<$int>> for $type<MIN, MAX> {
+            type Error = crate::DynBounded<$int>;
+            fn try_from(other: crate::DynBounded<$int>) -> Result<Self, Self::Error> {
+                if other.bounds() == crate::DynBounds::new(MIN, MAX) {
+                    Ok(Self::from(other.unchecked()))
+                } else {
+                    Err(other)
+                }
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl PartialEq<crate::DynBounded<$int>> for $int {
+            fn eq(&self, other: &crate::DynBounded<$int>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl PartialOrd<crate::DynBounded<$int>> for $int {
+            fn partial_cmp(&self, other: &crate::DynBounded<$int>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
@@ -0,0 +1,156 @@
+//! Helpers that load bounded values from environment variables.
+
+/// Adds `from_env` and `from_env_or` to a bounded type, along with the error type they return.
+macro_rules! derive_env {
+    ( $type: ident, $error: ident, $bound:ty, $int:ty; $range: literal ) => {
+        paste::paste! {
+        /// Describes why an environment variable couldn't be loaded.
+        #[derive(Debug, Clone)]
+        #[allow(variant_size_differences)]
+        enum [<$type FromEnvErrorKind>]<const MIN: $bound, const MAX: $bound> {
+            NotPresent,
+            NotUnicode,
+            Invalid(<$type<MIN, MAX> as FromStr>::Err),
+            OutOfBounds($error<MIN, MAX>),
+            DefaultOutOfBounds($error<MIN, MAX>),
+        }
+
+        /// The error that is returned when a bounded value can't be loaded from an environment variable. It names the variable, the value that was found and the allowed range.
+        #[derive(Debug, Clone)]
+        pub struct [<$type FromEnvError>]<const MIN: $bound, const MAX: $bound> {
+            variable: String,
+            value: Option<String>,
+            kind: [<$type FromEnvErrorKind>]<MIN, MAX>,
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> [<$type FromEnvError>]<MIN, MAX> {
+            /// Returns the name of the environment variable.
+            #[must_use]
+            pub fn variable(&self) -> &str {
+                &self.variable
+            }
+
+            /// Returns the value of the environment variable, or `None` if it isn't set or isn't valid unicode.
+            #[must_use]
+            pub fn value(&self) -> Option<&str> {
+                self.value.as_deref()
+            }
+
+            /// Returns whether the environment variable isn't set.
+            #[must_use]
+            pub fn is_not_present(&self) -> bool {
+                matches!(self.kind, [<$type FromEnvErrorKind>]::NotPresent)
+            }
+
+            /// Returns the bounds error if the environment variable holds an integer that is out of bounds.
+            #[must_use]
+            pub fn out_of_bounds(&self) -> Option<$error<MIN, MAX>> {
+                match self.kind {
+                    [<$type FromEnvErrorKind>]::OutOfBounds(err) => Some(err),
+                    _ => None,
+                }
+            }
+
+            /// Returns the bounds error if the variable isn't set and the default value is out of bounds.
+            #[must_use]
+            pub fn default_out_of_bounds(&self) -> Option<$error<MIN, MAX>> {
+                match self.kind {
+                    [<$type FromEnvErrorKind>]::DefaultOutOfBounds(err) => Some(err),
+                    _ => None,
+                }
+            }
+
+            /// Returns the lower bound.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn min_allowed(&self) -> $bound {
+                MIN
+            }
+
+            /// Returns the upper bound.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn max_allowed(&self) -> $bound {
+                MAX
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> std::fmt::Display for [<$type FromEnvError>]<MIN, MAX> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match &self.kind {
+                    [<$type FromEnvErrorKind>]::NotPresent => write!(f, "environment variable {} is not set", self.variable)?,
+                    [<$type FromEnvErrorKind>]::NotUnicode => write!(f, "environment variable {} is not valid unicode", self.variable)?,
+                    [<$type FromEnvErrorKind>]::Invalid(err) => write!(
+                        f,
+                        "environment variable {}={:?} is invalid ({})",
+                        self.variable,
+                        self.value().unwrap_or_default(),
+                        err
+                    )?,
+                    [<$type FromEnvErrorKind>]::OutOfBounds(_) => write!(
+                        f,
+                        "environment variable {}={:?} is out of bounds",
+                        self.variable,
+                        self.value().unwrap_or_default()
+                    )?,
+                    [<$type FromEnvErrorKind>]::DefaultOutOfBounds(err) => write!(
+                        f,
+                        "environment variable {} is not set and the default value {} is out of bounds",
+                        self.variable,
+                        err.value()
+                    )?,
+                }
+                write!(f, concat!(", allowed range is ", $range), MIN, MAX)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> std::error::Error for [<$type FromEnvError>]<MIN, MAX> {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match &self.kind {
+                    [<$type FromEnvErrorKind>]::Invalid(err) => Some(err),
+                    [<$type FromEnvErrorKind>]::OutOfBounds(err) | [<$type FromEnvErrorKind>]::DefaultOutOfBounds(err) => Some(err),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> $type<MIN, MAX> {
+            /// Loads the value from the environment variable `variable`.
+            /// # Errors
+            /// Fails if the variable isn't set, isn't an integer, or is out of bounds.
+            pub fn from_env(variable: &str) -> Result<Self, [<$type FromEnvError>]<MIN, MAX>> {
+                let error = |value: Option<String>, kind| [<$type FromEnvError>] {
+                    variable: variable.to_owned(),
+                    value,
+                    kind,
+                };
+                let value = std::env::var(variable).map_err(|err| match err {
+                    std::env::VarError::NotPresent => error(None, [<$type FromEnvErrorKind>]::NotPresent),
+                    std::env::VarError::NotUnicode(_) => error(None, [<$type FromEnvErrorKind>]::NotUnicode),
+                })?;
+                match Self::from_str(&value) {
+                    Ok(Self(Ok(val))) => Ok(Self(Ok(val))),
+                    Ok(Self(Err(err))) => Err(error(Some(value), [<$type FromEnvErrorKind>]::OutOfBounds(err))),
+                    Err(err) => Err(error(Some(value), [<$type FromEnvErrorKind>]::Invalid(err))),
+                }
+            }
+
+            /// Loads the value from the environment variable `variable`, or returns `default` if the variable isn't set.
+            /// # Errors
+            /// Fails if the variable is set, but isn't an integer or is out of bounds, or if it isn't set and `default` is out of bounds.
+            pub fn from_env_or(variable: &str, default: $int) -> Result<Self, [<$type FromEnvError>]<MIN, MAX>> {
+                match Self::from_env(variable) {
+                    Err(err) if err.is_not_present() => match Self::from(default) {
+                        Self(Err(default)) => Err([<$type FromEnvError>] {
+                            kind: [<$type FromEnvErrorKind>]::DefaultOutOfBounds(default),
+                            ..err
+                        }),
+                        default => Ok(default),
+                    },
+                    result => result,
+                }
+            }
+        }
+        }
+    };
+}
@@ -0,0 +1,74 @@
+//! Bounded types with exclusive bounds.
+//!
+//! `HalfOpenBoundedUsize<0, N>` holds `0..N`, which is what array lengths and offsets need, without writing `{ N - 1 }`. `ExclusiveBoundedI64<MIN, MAX>` excludes both bounds.
+//!
+//! They are generated by the same macros as the inclusive types, so they come with strict variants like `StrictHalfOpenBoundedUsize`, the environment and float helpers, the conversions between backing integers and the integrations behind features.
+//! They also compare with and convert to and from each other and the inclusive types.
+
+/// Derives the comparisons of `$name` with `$other`, for any bounds of both. An out-of-bounds value on either side compares false.
+macro_rules! derive_interval_comparisons {
+    ( $name: ident, $other: ident, $bound:ty ) => {
+        impl<const MIN: $bound, const MAX: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound> PartialEq<$other<OTHER_MIN, OTHER_MAX>>
+            for $name<MIN, MAX>
+        {
+            fn eq(&self, other: &$other<OTHER_MIN, OTHER_MAX>) -> bool {
+                match **other {
+                    Ok(other_val) => *self == other_val,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound> PartialOrd<$other<OTHER_MIN, OTHER_MAX>>
+            for $name<MIN, MAX>
+        {
+            fn partial_cmp(&self, other: &$other<OTHER_MIN, OTHER_MAX>) -> Option<Ordering> {
+                match **other {
+                    Ok(other_val) => self.partial_cmp(&other_val),
+                    Err(_) => None,
+                }
+            }
+        }
+    };
+}
+
+/// Derives the conversion from `$other` into `$name`, for any bounds of both. The value is checked against the new bounds.
+macro_rules! derive_interval_conversion {
+    ( $name: ident, $other: ident, $bound:ty; $doc: literal ) => {
+        #[doc = $doc]
+        impl<const MIN: $bound, const MAX: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound> From<$other<OTHER_MIN, OTHER_MAX>>
+            for $name<MIN, MAX>
+        {
+            fn from(other: $other<OTHER_MIN, OTHER_MAX>) -> Self {
+                Self::from(other.unchecked())
+            }
+        }
+    };
+}
+
+/// Generates the half-open and the fully exclusive variant of a bounded type, with the same strict variants and integrations as the inclusive type.
+/// They are named like the inclusive type with a `HalfOpen` or `Exclusive` prefix, and their error types have an additional `OutOfBoundsError` suffix.
+macro_rules! generate_exclusive {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        paste::paste! {
+        generate_bounded!([<HalfOpen $type>], [<HalfOpen $type OutOfBoundsError>], $bound, $int; >=, <, Some(MIN), MAX.checked_sub(1),
+            "{}..{}", "at least {} and less than {}", "the half-open range `MIN..MAX`");
+        generate_bounded!([<Exclusive $type>], [<Exclusive $type OutOfBoundsError>], $bound, $int; >, <, MIN.checked_add(1), MAX.checked_sub(1),
+            "{}..{} excluding both bounds", "greater than {} and less than {}", "the range `MIN..MAX`, excluding both `MIN` and `MAX`");
+
+        derive_interval_conversion!($type, [<HalfOpen $type>], $bound; "Converts into the inclusive form. The value is checked against the new bounds.");
+        derive_interval_conversion!($type, [<Exclusive $type>], $bound; "Converts into the inclusive form. The value is checked against the new bounds.");
+        derive_interval_conversion!([<HalfOpen $type>], $type, $bound; "Converts from the inclusive form. The value is checked against the new bounds.");
+        derive_interval_conversion!([<Exclusive $type>], $type, $bound; "Converts from the inclusive form. The value is checked against the new bounds.");
+        derive_interval_conversion!([<Exclusive $type>], [<HalfOpen $type>], $bound; "Converts between the half-open and the fully exclusive form. The value is checked against the new bounds.");
+        derive_interval_conversion!([<HalfOpen $type>], [<Exclusive $type>], $bound; "Converts between the half-open and the fully exclusive form. The value is checked against the new bounds.");
+
+        derive_interval_comparisons!([<HalfOpen $type>], [<HalfOpen $type>], $bound);
+        derive_interval_comparisons!([<Exclusive $type>], [<Exclusive $type>], $bound);
+        derive_interval_comparisons!($type, [<HalfOpen $type>], $bound);
+        derive_interval_comparisons!($type, [<Exclusive $type>], $bound);
+        derive_interval_comparisons!([<HalfOpen $type>], [<Exclusive $type>], $bound);
+        derive_interval_comparisons!([<Exclusive $type>], [<HalfOpen $type>], $bound);
+        }
+    };
+}
@@ -0,0 +1,137 @@
+//! Conversions from and comparisons with floating-point numbers.
+
+/// Describes how a float is rounded when it is converted into a bounded integer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds to the nearest integer, and to the nearest even integer if the float is exactly halfway between two integers.
+    HalfEven,
+    /// Rounds towards zero.
+    Truncate,
+}
+
+impl Rounding {
+    /// Rounds `val` to an integral float.
+    #[must_use]
+    pub fn round(self, val: f64) -> f64 {
+        match self {
+            Rounding::Floor => val.floor(),
+            Rounding::Ceil => val.ceil(),
+            Rounding::HalfEven => val.round_ties_even(),
+            Rounding::Truncate => val.trunc(),
+        }
+    }
+}
+
+/// The error that is returned when a float can't be converted into a bounded type.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FromFloatError {
+    /// The float is NaN.
+    NaN,
+    /// The rounded float is out of bounds. This includes infinities.
+    OutOfBounds(f64),
+}
+
+impl std::fmt::Display for FromFloatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromFloatError::NaN => write!(f, "NaN can't be converted into a bounded integer"),
+            FromFloatError::OutOfBounds(val) => write!(f, "rounded value {val} is out of bounds"),
+        }
+    }
+}
+
+impl std::error::Error for FromFloatError {}
+
+/// Derives the conversions from and comparisons with `f64` for a bounded type.
+macro_rules! derive_float_traits {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        impl<const MIN: $bound, const MAX: $bound> $type<MIN, MAX> {
+            /// Returns whether an integral float can be cast to $int without saturating.
+            /// `$int::MAX as f64` may be rounded up to the next power of two, which is then exactly the first value that doesn't fit.
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn fits_int(val: f64) -> bool {
+                val >= <$int>::MIN as f64 && val < <$int>::MAX as f64 + 1.0
+            }
+
+            /// Converts a float into a bounded value, after rounding it with the given rounding mode.
+            /// # Errors
+            /// Fails if the float is NaN or if the rounded float is out of bounds.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            pub fn try_from_f64(val: f64, rounding: crate::Rounding) -> Result<Self, crate::FromFloatError> {
+                if val.is_nan() {
+                    return Err(crate::FromFloatError::NaN);
+                }
+                let rounded = rounding.round(val);
+                if Self::fits_int(rounded) && Self::is_in_bounds(&(rounded as $int)) {
+                    Ok(Self(Ok(rounded as $int)))
+                } else {
+                    Err(crate::FromFloatError::OutOfBounds(rounded))
+                }
+            }
+
+            /// Converts a float into a bounded value, after rounding it with the given rounding mode. Values outside of the bounds (including infinities) are clamped to the smallest or largest allowed value.
+            /// Returns `None` if the float is NaN.
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            pub fn from_f64_clamped(val: f64, rounding: crate::Rounding) -> Option<Self> {
+                if val.is_nan() {
+                    return None;
+                }
+                // `as` saturates at $int::MIN and $int::MAX
+                Some(Self::clamp_to_bounds(rounding.round(val) as $int))
+            }
+
+            /// Returns the value as `f64`, rounded to the nearest float if it can't be represented exactly, or `None` if it is out of bounds.
+            #[must_use]
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            pub fn to_f64(&self) -> Option<f64> {
+                self.0.ok().map(|val| val as f64)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> PartialEq<f64> for $type<MIN, MAX> {
+            // will throw false if self is out of bounds or other is NaN
+            fn eq(&self, other: &f64) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> PartialOrd<f64> for $type<MIN, MAX> {
+            // compares exactly, without rounding self to a float
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+                let self_val = self.0.ok()?;
+                if other.is_nan() {
+                    None
+                } else if !Self::fits_int(other.floor()) {
+                    Some(if *other < 0.0 { Ordering::Greater } else { Ordering::Less })
+                } else {
+                    let other_floor = other.floor() as $int;
+                    match self_val.cmp(&other_floor) {
+                        // self is the integer part of other, so it's smaller if other has a fractional part
+                        Ordering::Equal if other.floor() < *other => Some(Ordering::Less),
+                        ordering => Some(ordering),
+                    }
+                }
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const MIN: $bound, const MAX: $bound> PartialEq<$type<MIN, MAX>> for f64 {
+            fn eq(&self, other: &$type<MIN, MAX>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const MIN: $bound, const MAX: $bound> PartialOrd<$type<MIN, MAX>> for f64 {
+            fn partial_cmp(&self, other: &$type<MIN, MAX>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
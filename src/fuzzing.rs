@@ -0,0 +1,42 @@
+//! Support for the `arbitrary` crate, so that fuzz targets can take bounded values as structured input.
+
+/// Implements `arbitrary::Arbitrary` for a bounded type and its strict variant. Only in-bounds values are generated.
+///
+/// The value is taken from the input modulo the size of the range, like `Unstructured::int_in_range` does, so values at the lower end are slightly more likely if the size isn't a power of two.
+macro_rules! derive_arbitrary {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        #[cfg(feature = "arbitrary")]
+        impl<'a, const MIN: $bound, const MAX: $bound> ::arbitrary::Arbitrary<'a> for $type<MIN, MAX> {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                // there are no valid values if the bounds are in the wrong order
+                let Some((first, last)) = Self::allowed_range() else {
+                    return Err(::arbitrary::Error::IncorrectFormat);
+                };
+                Ok(Self(Ok(u.int_in_range(first..=last)?)))
+            }
+
+            /// `int_in_range` consumes just enough bytes to cover the size of the range, and none if it only holds one value.
+            #[allow(clippy::cast_possible_truncation)]
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                let bytes = Self::allowed_range()
+                    .map_or(0, |(first, last)| (<$int>::BITS - last.abs_diff(first).leading_zeros()).div_ceil(8) as usize);
+                (bytes, Some(bytes))
+            }
+        }
+
+        paste::paste! {
+        #[cfg(feature = "arbitrary")]
+        impl<'a, const MIN: $bound, const MAX: $bound> ::arbitrary::Arbitrary<'a> for [<Strict $type>]<MIN, MAX> {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                $type::<MIN, MAX>::arbitrary(u)?
+                    .strict()
+                    .map_err(|_| ::arbitrary::Error::IncorrectFormat)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$type<MIN, MAX> as ::arbitrary::Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+        }
+    };
+}
@@ -1,7 +1,7 @@
 /*! Provides newtypes `BoundedI32`, `BoundedI64`, etc. which behave similar to their raw counterparts, but guarantee that the value is within a range that you specify.
 In contrast to other crates like this, these types are implemented using the newly stabilized const generics feature, which allows for simplifications that make the use of this type more intuitive and idiomatic.
 
-They are wrappers around a `Result`, but implement traits like `PartialEq<{Integer}>` and even `Ord<{Integer}>` that make them act like integers in many ways. Some traits (like `Add`, for example) are intentionally not implemented, since those would be invalid on out-of-bounds values.
+They are wrappers around a `Result`, but implement traits like `PartialEq<{Integer}>` and even `Ord<{Integer}>` that make them act like integers in many ways. Some traits (like `Add`, for example) are intentionally not implemented by default, since those would be invalid on out-of-bounds values. The `num-traits` feature implements `Add`, `Sub` and `Mul`, because its checked arithmetic traits require them.
 
 ## Example
  ```
@@ -25,6 +25,199 @@ assert_eq!(bounded_err != 11, true);
 assert_eq!(bounded_err > 5, false);
 ```
 
+## Strict types
+Every bounded type has a strict variant like `StrictBoundedI64`, which can only hold in-bounds values. Since it has no out-of-bounds state, it implements `Eq`, `Ord` and `Hash`, so it can be used as a map key or sort key.
+```
+use bounded_types::{BoundedU8, StrictBoundedU8};
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+
+let lenient: BoundedU8<0, 10> = 5.into();
+let strict = lenient.strict().unwrap();
+assert!(StrictBoundedU8::<0, 10>::try_from(11).is_err());
+
+let set: BTreeSet<StrictBoundedU8<0, 10>> = vec![strict, strict].into_iter().collect();
+assert_eq!(set.len(), 1);
+```
+
+## Defaults
+
+Every type implements `Default`, returning the allowed value that is closest to zero. For other defaults, `DefaultedBoundedU16<MIN, MAX, DEFAULT>` and friends wrap the bounded type, and only compile if `DEFAULT` is within the bounds. They are useful with `#[derive(Default)]` and `#[serde(default)]`.
+
+```
+use bounded_types::{BoundedI32, BoundedU8, DefaultedBoundedU16};
+
+assert!(BoundedU8::<1, 10>::default() == 1);
+assert!(BoundedI32::<-5, 5>::default() == 0);
+
+#[derive(Default)]
+struct Config {
+    port: DefaultedBoundedU16<1024, 65535, 8080>,
+}
+assert!(Config::default().port == 8080);
+```
+
+## Exclusive bounds
+
+Every bounded integer also comes with a half-open variant like `HalfOpenBoundedUsize<MIN, MAX>` for `MIN..MAX`, and a fully exclusive variant like `ExclusiveBoundedUsize<MIN, MAX>`. Their errors are named like them with an `OutOfBoundsError` suffix, like `HalfOpenBoundedUsizeOutOfBoundsError`. They convert to and from the inclusive types, checking the value against the new bounds, and compare with them. Everything else works like for the inclusive types: there are strict variants like `StrictHalfOpenBoundedUsize`, conversions between backing integers, the float and environment helpers, and the optional features.
+
+```
+use bounded_types::{BoundedUsize, HalfOpenBoundedUsize};
+
+const LEN: usize = 4;
+let offset: HalfOpenBoundedUsize<0, LEN> = 3.into();
+assert!(offset == 3);
+assert!(HalfOpenBoundedUsize::<0, LEN>::from(LEN).is_err());
+let inclusive: BoundedUsize<0, 3> = offset.into();
+assert!(inclusive == 3);
+assert!(offset.strict().unwrap().widen::<0, 8>() == 3);
+```
+
+## One-sided bounds
+
+If only one bound matters, `AtLeastI64<MIN>` and `AtMostI64<MAX>` (and the same for every other backing integer) avoid spelling out the other bound. Their errors, like `AtLeastI64OutOfBoundsError`, only mention the relevant bound, and they convert into and compare with the two-sided types.
+
+```
+use bounded_types::{AtLeastI64, AtMostU32};
+
+let non_negative: AtLeastI64<0> = (-1).into();
+assert_eq!(non_negative.unwrap_err().to_string(), "value -1 is out of bounds, it must be at least 0");
+assert!(AtMostU32::<1000>::from(1000) == 1000);
+```
+
+## Stepped values
+
+`SteppedBoundedU32<MIN, MAX, STEP>` (and the same for every other backing integer) only allows `MIN` plus multiples of `STEP`. Misaligned values are reported separately from out-of-bounds values, by an error type named like the stepped type with an `OutOfBoundsError` suffix, like `SteppedBoundedU32OutOfBoundsError`.
+
+```
+use bounded_types::SteppedBoundedU32;
+
+type BufferSize = SteppedBoundedU32<64, 4096, 64>;
+assert!(BufferSize::from(100).unwrap_err().is_misaligned());
+assert!(BufferSize::round_to_step(100) == 128);
+assert_eq!(BufferSize::values().count(), 64);
+```
+
+## Soft bounds
+
+Telemetry values often have a hard valid range and a narrower normal range. `SoftBoundedI32<MIN, MAX, WARN_LO, WARN_HI>` and friends behave like `BoundedI32<MIN, MAX>`, and additionally classify their value.
+
+```
+use bounded_types::{Classification, SoftBoundedI16, Warning};
+
+type BatteryTemperature = SoftBoundedI16<-40, 85, 0, 45>;
+assert!(BatteryTemperature::from(20).classify() == Classification::Normal);
+assert!(BatteryTemperature::from(60).classify() == Classification::Warning(Warning::High));
+assert!(BatteryTemperature::from(90).classify() == Classification::OutOfBounds);
+assert!(BatteryTemperature::from(90).is_err());
+```
+
+## Non-contiguous domains
+
+Domains like "any `i32` except 0" or "`1..=10` or `20..=30`" are described by a marker type that implements `Domain`, and used with `UnionBoundedI32<D>` and friends. Their errors, like `UnionBoundedI32OutOfBoundsError<D>`, report the nearest allowed sub-range.
+
+## Runtime bounds
+
+If the bounds are only known at runtime, `DynBounded<T>` carries them along with the value. `DynBounds<T>` is a `DeserializeSeed`, so values can be deserialized with bounds from a configuration file.
+
+```
+use bounded_types::{BoundedI64, DynBounds};
+use std::convert::TryFrom;
+
+let bounds = DynBounds::new(2_i64, 10);
+let bounded = bounds.bound(5);
+assert!(bounded == 5);
+assert!(bounds.bound(11).is_err());
+assert!(BoundedI64::<2, 10>::try_from(bounded).is_ok());
+```
+
+## Refinement types
+
+Range checks are only one kind of invariant. `Refined<T, P>` forces a value to satisfy the predicate `P`, which can be built from `InRange`, `Even`, `MultipleOf` and `NonZero` with `And`, `Or` and `Not` from the `predicates` module, or implemented for your own types. The bounded types are predicates as well: `Refined<u8, BoundedU8<0, 100>>` converts into `BoundedU8<0, 100>` and back without losing out-of-bounds values, its error converts into the `OutOfBoundsError` of the bounded type, and both are serialized the same way. `InRange` takes `i128` bounds, so use a bounded type as predicate for `u128` values above `i128::MAX`. Since `Refined` is generic over the value type, the strict variants and the feature integrations are only implemented for the bounded types.
+
+```
+use bounded_types::predicates::{And, Even, InRange};
+use bounded_types::{BoundedU8, Refined};
+
+type EvenPercentage = Refined<u8, And<Even, InRange<0, 100>>>;
+assert!(EvenPercentage::from(42) == 42);
+let odd = EvenPercentage::from(43);
+assert!(odd.unwrap_err().to_string() == "value 43 doesn't satisfy (even and 0..=100)");
+
+let percentage: Refined<u8, BoundedU8<0, 100>> = 101.into();
+let bounded: BoundedU8<0, 100> = percentage.into();
+assert!(bounded.unwrap_err().value() == 101);
+```
+
+## Reflection
+
+Every integer type implements the object-safe `AnyBounded` trait, so values of different bounded types can be inspected and edited as `dyn AnyBounded`, for example in an admin interface. Values and bounds are passed as `i128`, and `try_set_i128` keeps the old value if the new one isn't allowed.
+
+## Floats
+
+Floats are never converted implicitly. `try_from_f64` and `from_f64_clamped` take a `Rounding` mode, and bounded values can be compared with `f64` exactly.
+
+```
+use bounded_types::{BoundedI16, Rounding};
+
+let temperature = BoundedI16::<-40, 125>::try_from_f64(21.5, Rounding::HalfEven).unwrap();
+assert!(temperature == 22);
+assert!(temperature < 22.5);
+assert!(BoundedI16::<-40, 125>::try_from_f64(f64::NAN, Rounding::Floor).is_err());
+```
+
+Bounded floats can't use `f64` bounds, so `BoundedF64<NUM_MIN, NUM_MAX, DEN>` takes them as fractions `NUM_MIN / DEN..=NUM_MAX / DEN`. NaN is always out of bounds, and `StrictBoundedF64` is `Eq`, `Ord` and `Hash`.
+
+```
+use bounded_types::BoundedF64;
+
+type Probability = BoundedF64<0, 1, 1>;
+assert!(Probability::from(0.25) == 0.25);
+assert!(Probability::from(f64::NAN).is_err());
+assert!(Probability::from_f64_clamped(1.5).unwrap() == 1.0);
+```
+
+## Characters
+
+`BoundedChar<'a', 'z'>` bounds characters the same way, converts into `BoundedU32` code points and can iterate over its range.
+
+```
+use bounded_types::BoundedChar;
+
+type Lowercase = BoundedChar<'a', 'z'>;
+assert!(Lowercase::from('q') == 'q');
+assert!(Lowercase::from('Q').is_err());
+assert_eq!(Lowercase::values().count(), 26);
+```
+
+## Durations
+
+`BoundedDuration<MIN_MS, MAX_MS>` wraps `std::time::Duration` with bounds in milliseconds. It parses strings like `"250ms"` or `"5s"`, and both it and `StrictBoundedDuration` are serialized in the same format.
+
+```
+use bounded_types::BoundedDuration;
+use std::time::Duration;
+
+type Timeout = BoundedDuration<100, 5000>;
+let timeout: Timeout = "250ms".parse().unwrap();
+assert!(timeout == Duration::from_millis(250));
+assert!(timeout.saturating_add(Duration::from_secs(10)) == Duration::from_secs(5));
+```
+
+## Modular arithmetic
+
+`Mod<N>` is an integer modulo `N`, with `Add`, `Sub`, `Mul`, `Neg`, `pow`, `inverse` and `Div`. Division multiplies with the inverse, which exists if the divisor is coprime to `N`; `checked_div` returns `None` otherwise. It converts into `BoundedU64<0, {N - 1}>`.
+
+```
+use bounded_types::Mod;
+
+let hour = Mod::<24>::new(22);
+assert!(hour + Mod::new(5) == 3);
+assert!(Mod::<13>::new(2).pow(12) == 1);
+assert!(Mod::<13>::new(1) / Mod::new(2) == 7);
+assert!(Mod::<12>::new(5).checked_div(Mod::new(4)) == None);
+```
+
 ## Memory use
 ```
 use bounded_types::*;
@@ -36,6 +229,13 @@ assert!(size_of::<Option<i64>>() == size_of::<BoundedI64<0, 10>>());
 assert!(size_of::<Option<i128>>() == size_of::<BoundedI128<0, 10>>());
 // etc. you get the idea
 ```
+
+## Optional features
+- `arbitrary`: Implements `arbitrary::Arbitrary` for all bounded types. Only in-bounds values are generated, so fuzz targets don't waste iterations on rejected inputs. Like `Unstructured::int_in_range`, the input is reduced modulo the size of the range, which slightly favors the lower end of ranges whose size isn't a power of two.
+- `rusqlite`: Implements `ToSql` and `FromSql` from `rusqlite`. Reading an out-of-bounds value fails with an error like `BoundedI64FromSqlError`, which names the range, also for values that don't fit into the backing integer.
+- `postgres`: Implements `ToSql` and `FromSql` from `postgres-types` for `INT2`, `INT4` and `INT8` columns, with the same range checks.
+- `clap`: Adds a value parser for every bounded type, so `#[arg]` fields can be bounded. Out-of-bounds arguments are rejected with an error that names the range, and `range_help()` returns the same description for the help of the argument, like `#[arg(help = format!("Port to listen on, {}", Port::range_help()))]`, so `--help` shows the range.
+- `num-traits`: Implements `Bounded`, `ToPrimitive`, `FromPrimitive`, `NumCast` and the checked and saturating arithmetic traits from `num-traits`. Since those traits require them, `Add`, `Sub` and `Mul` are implemented as well; an out-of-bounds result is stored as an out-of-bounds value, and a result that overflows the backing integer is stored saturated, but out of bounds.
 */
 #![deny(
     deprecated_in_future,
@@ -45,15 +245,12 @@ assert!(size_of::<Option<i128>>() == size_of::<BoundedI128<0, 10>>());
     missing_crate_level_docs,
     missing_debug_implementations,
     missing_docs,
-    private_in_public,
     rust_2018_compatibility,
     rust_2018_idioms,
     trivial_casts,
     trivial_numeric_casts,
     unsafe_code,
     unused_qualifications,
-    trivial_casts,
-    trivial_numeric_casts,
     unused_crate_dependencies,
     unused_lifetimes,
     variant_size_differences
@@ -74,8 +271,331 @@ pub use crate::u64::BoundedU64;
 pub use crate::u8::BoundedU8;
 pub use crate::usize::BoundedUsize;
 
+pub use crate::i128::StrictBoundedI128;
+pub use crate::i16::StrictBoundedI16;
+pub use crate::i32::StrictBoundedI32;
+pub use crate::i64::StrictBoundedI64;
+pub use crate::i8::StrictBoundedI8;
+pub use crate::isize::StrictBoundedIsize;
+
+pub use crate::u128::StrictBoundedU128;
+pub use crate::u16::StrictBoundedU16;
+pub use crate::u32::StrictBoundedU32;
+pub use crate::u64::StrictBoundedU64;
+pub use crate::u8::StrictBoundedU8;
+pub use crate::usize::StrictBoundedUsize;
+
+pub use crate::i128::AtLeastI128;
+pub use crate::i16::AtLeastI16;
+pub use crate::i32::AtLeastI32;
+pub use crate::i64::AtLeastI64;
+pub use crate::i8::AtLeastI8;
+pub use crate::isize::AtLeastIsize;
+
+pub use crate::u128::AtLeastU128;
+pub use crate::u16::AtLeastU16;
+pub use crate::u32::AtLeastU32;
+pub use crate::u64::AtLeastU64;
+pub use crate::u8::AtLeastU8;
+pub use crate::usize::AtLeastUsize;
+
+pub use crate::i128::AtMostI128;
+pub use crate::i16::AtMostI16;
+pub use crate::i32::AtMostI32;
+pub use crate::i64::AtMostI64;
+pub use crate::i8::AtMostI8;
+pub use crate::isize::AtMostIsize;
+
+pub use crate::u128::AtMostU128;
+pub use crate::u16::AtMostU16;
+pub use crate::u32::AtMostU32;
+pub use crate::u64::AtMostU64;
+pub use crate::u8::AtMostU8;
+pub use crate::usize::AtMostUsize;
+
+pub use crate::i128::HalfOpenBoundedI128;
+pub use crate::i16::HalfOpenBoundedI16;
+pub use crate::i32::HalfOpenBoundedI32;
+pub use crate::i64::HalfOpenBoundedI64;
+pub use crate::i8::HalfOpenBoundedI8;
+pub use crate::isize::HalfOpenBoundedIsize;
+
+pub use crate::u128::HalfOpenBoundedU128;
+pub use crate::u16::HalfOpenBoundedU16;
+pub use crate::u32::HalfOpenBoundedU32;
+pub use crate::u64::HalfOpenBoundedU64;
+pub use crate::u8::HalfOpenBoundedU8;
+pub use crate::usize::HalfOpenBoundedUsize;
+
+pub use crate::i128::ExclusiveBoundedI128;
+pub use crate::i16::ExclusiveBoundedI16;
+pub use crate::i32::ExclusiveBoundedI32;
+pub use crate::i64::ExclusiveBoundedI64;
+pub use crate::i8::ExclusiveBoundedI8;
+pub use crate::isize::ExclusiveBoundedIsize;
+
+pub use crate::u128::ExclusiveBoundedU128;
+pub use crate::u16::ExclusiveBoundedU16;
+pub use crate::u32::ExclusiveBoundedU32;
+pub use crate::u64::ExclusiveBoundedU64;
+pub use crate::u8::ExclusiveBoundedU8;
+pub use crate::usize::ExclusiveBoundedUsize;
+
+pub use crate::i128::StrictHalfOpenBoundedI128;
+pub use crate::i16::StrictHalfOpenBoundedI16;
+pub use crate::i32::StrictHalfOpenBoundedI32;
+pub use crate::i64::StrictHalfOpenBoundedI64;
+pub use crate::i8::StrictHalfOpenBoundedI8;
+pub use crate::isize::StrictHalfOpenBoundedIsize;
+
+pub use crate::u128::StrictHalfOpenBoundedU128;
+pub use crate::u16::StrictHalfOpenBoundedU16;
+pub use crate::u32::StrictHalfOpenBoundedU32;
+pub use crate::u64::StrictHalfOpenBoundedU64;
+pub use crate::u8::StrictHalfOpenBoundedU8;
+pub use crate::usize::StrictHalfOpenBoundedUsize;
+
+pub use crate::i128::StrictExclusiveBoundedI128;
+pub use crate::i16::StrictExclusiveBoundedI16;
+pub use crate::i32::StrictExclusiveBoundedI32;
+pub use crate::i64::StrictExclusiveBoundedI64;
+pub use crate::i8::StrictExclusiveBoundedI8;
+pub use crate::isize::StrictExclusiveBoundedIsize;
+
+pub use crate::u128::StrictExclusiveBoundedU128;
+pub use crate::u16::StrictExclusiveBoundedU16;
+pub use crate::u32::StrictExclusiveBoundedU32;
+pub use crate::u64::StrictExclusiveBoundedU64;
+pub use crate::u8::StrictExclusiveBoundedU8;
+pub use crate::usize::StrictExclusiveBoundedUsize;
+
+pub use crate::i128::SteppedBoundedI128;
+pub use crate::i16::SteppedBoundedI16;
+pub use crate::i32::SteppedBoundedI32;
+pub use crate::i64::SteppedBoundedI64;
+pub use crate::i8::SteppedBoundedI8;
+pub use crate::isize::SteppedBoundedIsize;
+
+pub use crate::u128::SteppedBoundedU128;
+pub use crate::u16::SteppedBoundedU16;
+pub use crate::u32::SteppedBoundedU32;
+pub use crate::u64::SteppedBoundedU64;
+pub use crate::u8::SteppedBoundedU8;
+pub use crate::usize::SteppedBoundedUsize;
+
+pub use crate::i128::UnionBoundedI128;
+pub use crate::i16::UnionBoundedI16;
+pub use crate::i32::UnionBoundedI32;
+pub use crate::i64::UnionBoundedI64;
+pub use crate::i8::UnionBoundedI8;
+pub use crate::isize::UnionBoundedIsize;
+
+pub use crate::u128::UnionBoundedU128;
+pub use crate::u16::UnionBoundedU16;
+pub use crate::u32::UnionBoundedU32;
+pub use crate::u64::UnionBoundedU64;
+pub use crate::u8::UnionBoundedU8;
+pub use crate::usize::UnionBoundedUsize;
+
+pub use crate::i128::SoftBoundedI128;
+pub use crate::i16::SoftBoundedI16;
+pub use crate::i32::SoftBoundedI32;
+pub use crate::i64::SoftBoundedI64;
+pub use crate::i8::SoftBoundedI8;
+pub use crate::isize::SoftBoundedIsize;
+
+pub use crate::u128::SoftBoundedU128;
+pub use crate::u16::SoftBoundedU16;
+pub use crate::u32::SoftBoundedU32;
+pub use crate::u64::SoftBoundedU64;
+pub use crate::u8::SoftBoundedU8;
+pub use crate::usize::SoftBoundedUsize;
+
+pub use crate::i128::DefaultedBoundedI128;
+pub use crate::i16::DefaultedBoundedI16;
+pub use crate::i32::DefaultedBoundedI32;
+pub use crate::i64::DefaultedBoundedI64;
+pub use crate::i8::DefaultedBoundedI8;
+pub use crate::isize::DefaultedBoundedIsize;
+
+pub use crate::u128::DefaultedBoundedU128;
+pub use crate::u16::DefaultedBoundedU16;
+pub use crate::u32::DefaultedBoundedU32;
+pub use crate::u64::DefaultedBoundedU64;
+pub use crate::u8::DefaultedBoundedU8;
+pub use crate::usize::DefaultedBoundedUsize;
+
+pub use crate::union::Domain;
+
+pub use crate::any::{AnyBounded, AnyOutOfBoundsError};
+pub use crate::bounded_char::{BoundedChar, CharOutOfBoundsError, StrictBoundedChar};
+pub use crate::bounded_duration::{BoundedDuration, DurationOutOfBoundsError, ParseDurationError, StrictBoundedDuration};
+pub use crate::bounded_float::{BoundedF64, FloatOutOfBoundsError, StrictBoundedF64};
+pub use crate::dynamic::{DynBounded, DynBounds, DynOutOfBoundsError};
+pub use crate::float::{FromFloatError, Rounding};
+pub use crate::modular::Mod;
+pub use crate::refined::{predicates, Predicate, Refined, RefinementError};
+pub use crate::soft::{Classification, Warning};
+pub use crate::{
+    i128::BoundedI128FromEnvError, i16::BoundedI16FromEnvError, i32::BoundedI32FromEnvError,
+    i64::BoundedI64FromEnvError, i8::BoundedI8FromEnvError, isize::BoundedIsizeFromEnvError,
+    u128::BoundedU128FromEnvError, u16::BoundedU16FromEnvError, u32::BoundedU32FromEnvError,
+    u64::BoundedU64FromEnvError, u8::BoundedU8FromEnvError, usize::BoundedUsizeFromEnvError,
+};
+pub use crate::{
+    i128::HalfOpenBoundedI128FromEnvError, i16::HalfOpenBoundedI16FromEnvError,
+    i32::HalfOpenBoundedI32FromEnvError, i64::HalfOpenBoundedI64FromEnvError,
+    i8::HalfOpenBoundedI8FromEnvError, isize::HalfOpenBoundedIsizeFromEnvError,
+    u128::HalfOpenBoundedU128FromEnvError, u16::HalfOpenBoundedU16FromEnvError,
+    u32::HalfOpenBoundedU32FromEnvError, u64::HalfOpenBoundedU64FromEnvError,
+    u8::HalfOpenBoundedU8FromEnvError, usize::HalfOpenBoundedUsizeFromEnvError,
+};
+pub use crate::{
+    i128::ExclusiveBoundedI128FromEnvError, i16::ExclusiveBoundedI16FromEnvError,
+    i32::ExclusiveBoundedI32FromEnvError, i64::ExclusiveBoundedI64FromEnvError,
+    i8::ExclusiveBoundedI8FromEnvError, isize::ExclusiveBoundedIsizeFromEnvError,
+    u128::ExclusiveBoundedU128FromEnvError, u16::ExclusiveBoundedU16FromEnvError,
+    u32::ExclusiveBoundedU32FromEnvError, u64::ExclusiveBoundedU64FromEnvError,
+    u8::ExclusiveBoundedU8FromEnvError, usize::ExclusiveBoundedUsizeFromEnvError,
+};
+
+pub use crate::{
+    i128::AtLeastI128OutOfBoundsError, i16::AtLeastI16OutOfBoundsError, i32::AtLeastI32OutOfBoundsError,
+    i64::AtLeastI64OutOfBoundsError, i8::AtLeastI8OutOfBoundsError, isize::AtLeastIsizeOutOfBoundsError,
+    u128::AtLeastU128OutOfBoundsError, u16::AtLeastU16OutOfBoundsError, u32::AtLeastU32OutOfBoundsError,
+    u64::AtLeastU64OutOfBoundsError, u8::AtLeastU8OutOfBoundsError, usize::AtLeastUsizeOutOfBoundsError,
+};
+pub use crate::{
+    i128::AtMostI128OutOfBoundsError, i16::AtMostI16OutOfBoundsError, i32::AtMostI32OutOfBoundsError,
+    i64::AtMostI64OutOfBoundsError, i8::AtMostI8OutOfBoundsError, isize::AtMostIsizeOutOfBoundsError,
+    u128::AtMostU128OutOfBoundsError, u16::AtMostU16OutOfBoundsError, u32::AtMostU32OutOfBoundsError,
+    u64::AtMostU64OutOfBoundsError, u8::AtMostU8OutOfBoundsError, usize::AtMostUsizeOutOfBoundsError,
+};
+pub use crate::{
+    i128::HalfOpenBoundedI128OutOfBoundsError, i16::HalfOpenBoundedI16OutOfBoundsError,
+    i32::HalfOpenBoundedI32OutOfBoundsError, i64::HalfOpenBoundedI64OutOfBoundsError,
+    i8::HalfOpenBoundedI8OutOfBoundsError, isize::HalfOpenBoundedIsizeOutOfBoundsError,
+    u128::HalfOpenBoundedU128OutOfBoundsError, u16::HalfOpenBoundedU16OutOfBoundsError,
+    u32::HalfOpenBoundedU32OutOfBoundsError, u64::HalfOpenBoundedU64OutOfBoundsError,
+    u8::HalfOpenBoundedU8OutOfBoundsError, usize::HalfOpenBoundedUsizeOutOfBoundsError,
+};
+pub use crate::{
+    i128::ExclusiveBoundedI128OutOfBoundsError, i16::ExclusiveBoundedI16OutOfBoundsError,
+    i32::ExclusiveBoundedI32OutOfBoundsError, i64::ExclusiveBoundedI64OutOfBoundsError,
+    i8::ExclusiveBoundedI8OutOfBoundsError, isize::ExclusiveBoundedIsizeOutOfBoundsError,
+    u128::ExclusiveBoundedU128OutOfBoundsError, u16::ExclusiveBoundedU16OutOfBoundsError,
+    u32::ExclusiveBoundedU32OutOfBoundsError, u64::ExclusiveBoundedU64OutOfBoundsError,
+    u8::ExclusiveBoundedU8OutOfBoundsError, usize::ExclusiveBoundedUsizeOutOfBoundsError,
+};
+pub use crate::{
+    i128::SteppedBoundedI128OutOfBoundsError, i16::SteppedBoundedI16OutOfBoundsError,
+    i32::SteppedBoundedI32OutOfBoundsError, i64::SteppedBoundedI64OutOfBoundsError,
+    i8::SteppedBoundedI8OutOfBoundsError, isize::SteppedBoundedIsizeOutOfBoundsError,
+    u128::SteppedBoundedU128OutOfBoundsError, u16::SteppedBoundedU16OutOfBoundsError,
+    u32::SteppedBoundedU32OutOfBoundsError, u64::SteppedBoundedU64OutOfBoundsError,
+    u8::SteppedBoundedU8OutOfBoundsError, usize::SteppedBoundedUsizeOutOfBoundsError,
+};
+pub use crate::{
+    i128::UnionBoundedI128OutOfBoundsError, i16::UnionBoundedI16OutOfBoundsError,
+    i32::UnionBoundedI32OutOfBoundsError, i64::UnionBoundedI64OutOfBoundsError,
+    i8::UnionBoundedI8OutOfBoundsError, isize::UnionBoundedIsizeOutOfBoundsError,
+    u128::UnionBoundedU128OutOfBoundsError, u16::UnionBoundedU16OutOfBoundsError,
+    u32::UnionBoundedU32OutOfBoundsError, u64::UnionBoundedU64OutOfBoundsError,
+    u8::UnionBoundedU8OutOfBoundsError, usize::UnionBoundedUsizeOutOfBoundsError,
+};
+
+#[cfg(any(feature = "rusqlite", feature = "postgres"))]
+pub use crate::{
+    i128::BoundedI128FromSqlError, i16::BoundedI16FromSqlError, i32::BoundedI32FromSqlError,
+    i64::BoundedI64FromSqlError, i8::BoundedI8FromSqlError, isize::BoundedIsizeFromSqlError,
+    u128::BoundedU128FromSqlError, u16::BoundedU16FromSqlError, u32::BoundedU32FromSqlError,
+    u64::BoundedU64FromSqlError, u8::BoundedU8FromSqlError, usize::BoundedUsizeFromSqlError,
+};
+#[cfg(any(feature = "rusqlite", feature = "postgres"))]
+pub use crate::{
+    i128::HalfOpenBoundedI128FromSqlError, i16::HalfOpenBoundedI16FromSqlError,
+    i32::HalfOpenBoundedI32FromSqlError, i64::HalfOpenBoundedI64FromSqlError,
+    i8::HalfOpenBoundedI8FromSqlError, isize::HalfOpenBoundedIsizeFromSqlError,
+    u128::HalfOpenBoundedU128FromSqlError, u16::HalfOpenBoundedU16FromSqlError,
+    u32::HalfOpenBoundedU32FromSqlError, u64::HalfOpenBoundedU64FromSqlError,
+    u8::HalfOpenBoundedU8FromSqlError, usize::HalfOpenBoundedUsizeFromSqlError,
+};
+#[cfg(any(feature = "rusqlite", feature = "postgres"))]
+pub use crate::{
+    i128::ExclusiveBoundedI128FromSqlError, i16::ExclusiveBoundedI16FromSqlError,
+    i32::ExclusiveBoundedI32FromSqlError, i64::ExclusiveBoundedI64FromSqlError,
+    i8::ExclusiveBoundedI8FromSqlError, isize::ExclusiveBoundedIsizeFromSqlError,
+    u128::ExclusiveBoundedU128FromSqlError, u16::ExclusiveBoundedU16FromSqlError,
+    u32::ExclusiveBoundedU32FromSqlError, u64::ExclusiveBoundedU64FromSqlError,
+    u8::ExclusiveBoundedU8FromSqlError, usize::ExclusiveBoundedUsizeFromSqlError,
+};
+
+#[cfg(feature = "clap")]
+pub use crate::{
+    i128::BoundedI128ValueParser, i16::BoundedI16ValueParser, i32::BoundedI32ValueParser,
+    i64::BoundedI64ValueParser, i8::BoundedI8ValueParser, isize::BoundedIsizeValueParser,
+    u128::BoundedU128ValueParser, u16::BoundedU16ValueParser, u32::BoundedU32ValueParser,
+    u64::BoundedU64ValueParser, u8::BoundedU8ValueParser, usize::BoundedUsizeValueParser,
+};
+#[cfg(feature = "clap")]
+pub use crate::{
+    i128::HalfOpenBoundedI128ValueParser, i16::HalfOpenBoundedI16ValueParser,
+    i32::HalfOpenBoundedI32ValueParser, i64::HalfOpenBoundedI64ValueParser,
+    i8::HalfOpenBoundedI8ValueParser, isize::HalfOpenBoundedIsizeValueParser,
+    u128::HalfOpenBoundedU128ValueParser, u16::HalfOpenBoundedU16ValueParser,
+    u32::HalfOpenBoundedU32ValueParser, u64::HalfOpenBoundedU64ValueParser,
+    u8::HalfOpenBoundedU8ValueParser, usize::HalfOpenBoundedUsizeValueParser,
+};
+#[cfg(feature = "clap")]
+pub use crate::{
+    i128::ExclusiveBoundedI128ValueParser, i16::ExclusiveBoundedI16ValueParser,
+    i32::ExclusiveBoundedI32ValueParser, i64::ExclusiveBoundedI64ValueParser,
+    i8::ExclusiveBoundedI8ValueParser, isize::ExclusiveBoundedIsizeValueParser,
+    u128::ExclusiveBoundedU128ValueParser, u16::ExclusiveBoundedU16ValueParser,
+    u32::ExclusiveBoundedU32ValueParser, u64::ExclusiveBoundedU64ValueParser,
+    u8::ExclusiveBoundedU8ValueParser, usize::ExclusiveBoundedUsizeValueParser,
+};
+
+#[macro_use]
+mod any;
+#[macro_use]
+mod bounded_value;
+mod bounded_char;
+mod bounded_duration;
+mod bounded_float;
+mod modular;
+#[macro_use]
+mod cli;
 #[macro_use]
-/// Derives traits that define the relation to other numeric types. Like From, PartialEq, PartialOrd.
+mod default;
+#[macro_use]
+mod dynamic;
+#[macro_use]
+mod env;
+#[macro_use]
+mod exclusive;
+#[macro_use]
+mod float;
+#[macro_use]
+mod fuzzing;
+#[macro_use]
+mod num;
+#[macro_use]
+mod one_sided;
+#[macro_use]
+mod refined;
+#[macro_use]
+mod strict;
+#[macro_use]
+mod soft;
+#[macro_use]
+mod sql;
+#[macro_use]
+mod stepped;
+#[macro_use]
+mod union;
+
+/// Derives traits that define the relation to other numeric types. Like `From`, `PartialEq`, `PartialOrd`.
 macro_rules! derive_numeric_traits {
     ( $type: ident, $bound:ty, $int:ty; $( $numeric:ty ),* )  => {
         $(
@@ -90,8 +610,12 @@ macro_rules! derive_numeric_traits {
                             Self::out_of_bounds(this)
                         }
                     }
-                    // if we try to cast from a number that cannot be parsed as $int, we save $int::MAX as error value
-                    Err(_) => Self::out_of_bounds(<$int>::MAX),
+                    // if we try to cast from a number that cannot be parsed as $int, we save $int::MIN or $int::MAX as error value, whichever is closer
+                    #[allow(clippy::useless_conversion)]
+                    Err(_) => match i128::try_from(other) {
+                        Ok(other_val) if other_val < 0 => Self::out_of_bounds(<$int>::MIN),
+                        _ => Self::out_of_bounds(<$int>::MAX),
+                    },
                 }
             }
         }
@@ -128,7 +652,7 @@ macro_rules! derive_numeric_traits {
 
 
         impl<const MIN: $bound, const MAX: $bound> PartialEq<$numeric> for $type<MIN, MAX> {
-            // will throw false if values don't match or Numeric can't be cast as $int
+            // will throw false if values don't match or Numeric can't be cast as $int, since then it's outside of the range of self
             fn eq(&self, other: &$numeric) -> bool {
                 match (&self.0, <$int>::try_from(*other)) {
                     (&Ok(val), Ok(other)) => val == other,
@@ -138,10 +662,16 @@ macro_rules! derive_numeric_traits {
         }
 
         impl<const MIN: $bound, const MAX: $bound> PartialOrd<$numeric> for $type<MIN, MAX> {
-            // will throw false if values don't match or Numeric can't be cast as $int
+            // will throw false if self is out of bounds
+            #[allow(clippy::useless_conversion)]
             fn partial_cmp(&self, other: &$numeric) -> Option<Ordering> {
                 match (&self.0, <$int>::try_from(*other)) {
                     (Ok(self_val), Ok(other_val)) => self_val.partial_cmp(&other_val),
+                    // if Numeric can't be cast as $int, it is either below $int::MIN or above $int::MAX
+                    (Ok(_), Err(_)) => match i128::try_from(*other) {
+                        Ok(other_val) if other_val < 0 => Some(Ordering::Greater),
+                        _ => Some(Ordering::Less),
+                    },
                     _ => None,
                 }
             }
@@ -165,44 +695,107 @@ macro_rules! derive_numeric_traits {
     };
 }
 
+/// Derives `PartialEq` and `PartialOrd` between a bounded type and other bounded types, which may have different bounds or bound types.
+/// Values are compared like integers, and comparisons with out-of-bounds values always return `false`.
+macro_rules! derive_bounded_comparisons {
+    ( $type: ident, $bound:ty; $( $other:ident $other_bound:ty ),* ) => {
+        $(
+
+        impl<const MIN: $bound, const MAX: $bound, const OTHER_MIN: $other_bound, const OTHER_MAX: $other_bound>
+            PartialEq<crate::$other<OTHER_MIN, OTHER_MAX>> for $type<MIN, MAX>
+        {
+            fn eq(&self, other: &crate::$other<OTHER_MIN, OTHER_MAX>) -> bool {
+                match **other {
+                    Ok(other_val) => *self == other_val,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const OTHER_MIN: $other_bound, const OTHER_MAX: $other_bound>
+            PartialOrd<crate::$other<OTHER_MIN, OTHER_MAX>> for $type<MIN, MAX>
+        {
+            fn partial_cmp(&self, other: &crate::$other<OTHER_MIN, OTHER_MAX>) -> Option<Ordering> {
+                match **other {
+                    Ok(other_val) => self.partial_cmp(&other_val),
+                    Err(_) => None,
+                }
+            }
+        }
+
+        )*
+    };
+}
+
+/// Derives conversions from bounded types with other backing integers, for the inclusive, the half-open and the fully exclusive type.
+/// If the backing integer of the source converts losslessly into the target integer, `From` is implemented, otherwise `TryFrom`.
+/// Out-of-bounds values are converted using the value that was attempted to be stored, and are checked against the new bounds.
+macro_rules! derive_bounded_conversions {
+    ( @impl $type: ident, $error: ident, $bound:ty; from $( $from:ident $from_bound:ty ),*; try_from $( $try_from:ident $try_from_bound:ty ),* ) => {
+        $(
+        impl<const MIN: $bound, const MAX: $bound, const OTHER_MIN: $from_bound, const OTHER_MAX: $from_bound>
+            From<crate::$from<OTHER_MIN, OTHER_MAX>> for $type<MIN, MAX>
+        {
+            fn from(other: crate::$from<OTHER_MIN, OTHER_MAX>) -> Self {
+                Self::from(other.unchecked())
+            }
+        }
+        )*
+
+        $(
+        impl<const MIN: $bound, const MAX: $bound, const OTHER_MIN: $try_from_bound, const OTHER_MAX: $try_from_bound>
+            TryFrom<crate::$try_from<OTHER_MIN, OTHER_MAX>> for $type<MIN, MAX>
+        {
+            type Error = $error<MIN, MAX>;
+            fn try_from(other: crate::$try_from<OTHER_MIN, OTHER_MAX>) -> Result<Self, Self::Error> {
+                let converted = Self::from(other.unchecked());
+                converted.0.map(|_| converted)
+            }
+        }
+        )*
+    };
+    ( $type: ident, $bound:ty; from $( $from:ident $from_bound:ty ),*; try_from $( $try_from:ident $try_from_bound:ty ),* ) => {
+        paste::paste! {
+        derive_bounded_conversions!(@impl $type, OutOfBoundsError, $bound;
+            from $( $from $from_bound ),*; try_from $( $try_from $try_from_bound ),*);
+        derive_bounded_conversions!(@impl [<HalfOpen $type>], [<HalfOpen $type OutOfBoundsError>], $bound;
+            from $( [<HalfOpen $from>] $from_bound ),*; try_from $( [<HalfOpen $try_from>] $try_from_bound ),*);
+        derive_bounded_conversions!(@impl [<Exclusive $type>], [<Exclusive $type OutOfBoundsError>], $bound;
+            from $( [<Exclusive $from>] $from_bound ),*; try_from $( [<Exclusive $try_from>] $try_from_bound ),*);
+        }
+    };
+}
+
 // /// Numeric type stored within Unbounded, the type produced after operations are performed on `BoundedI64` elements. This should be larger or equal in size to Int.
 // /// Int = `UnboundedVal` seems natural for Int = i32, but for Int = usize, you might want `UnboundedVal` to be larger (like i128), so Int and `UnboundedVal` are separate.
 // type UnboundedVal = i64;
 
-/// Generates a bounded type with the specified type name, bound type and value type.
-#[macro_use]
-macro_rules! generate_type {
-    ( $type: ident, $bound:ty, $int:ty )   => {
-        use derive_more::Constructor;
-        use shrinkwraprs::Shrinkwrap; //derives Deref, Borrow and AsRef
-        use std::cmp::Ordering;
-        use std::cmp::{PartialEq, PartialOrd};
-        use std::convert::TryFrom;
-        use std::fmt::Debug;
-        use std::str::FromStr;
-        use serde::{Serialize, Deserialize};
-
+/// Generates a bounded type and its error type, along with the strict variant and the integrations that all bounded types share.
+/// `$lower` and `$upper` are the comparisons that an in-bounds value has to pass against `MIN` and `MAX`, and `$first` and `$last` are the smallest and the largest allowed value, if there are any.
+/// `$range` and `$help` describe the allowed range in error messages, and `$doc` in doc comments.
+macro_rules! generate_bounded {
+    ( $type: ident, $error: ident, $bound:ty, $int:ty; $lower: tt, $upper: tt, $first: expr, $last: expr, $range: literal, $help: literal, $doc: literal ) => {
 #[derive(Shrinkwrap, Constructor, Copy, Clone)]
 #[derive(Serialize, Deserialize)]
-/// The error that is returned when you attempt to assign an out-of-bounds value to a bounded type. This is stored as pointer so that enums containing it won't take up too much space.
-pub struct OutOfBoundsError<const MIN: $bound, const MAX: $bound>($int);
+#[doc = concat!("The error that is returned when you attempt to assign an out-of-bounds value to a `", stringify!($type), "`.")]
+pub struct $error<const MIN: $bound, const MAX: $bound>($int);
 
 
-impl<const MIN: $bound, const MAX: $bound> OutOfBoundsError<MIN, MAX> {
-    /// Returns a reference to the value that was attempted to be passed.
+impl<const MIN: $bound, const MAX: $bound> $error<MIN, MAX> {
+    /// Returns the value that was attempted to be passed.
     #[must_use]
     pub fn value(&self) -> $int {
         self.0
     }
 
-    /// Returns a reference to the smallest allowed value.
+    /// Returns the lower bound.
     #[must_use]
     #[allow(clippy::unused_self)]
     pub fn min_allowed(&self) -> $bound {
         MIN
     }
 
-    /// Returns a reference to the largest allowed value.
+    /// Returns the upper bound.
     #[must_use]
     #[allow(clippy::unused_self)]
     pub fn max_allowed(&self) -> $bound {
@@ -210,9 +803,9 @@ impl<const MIN: $bound, const MAX: $bound> OutOfBoundsError<MIN, MAX> {
     }
 }
 
-impl<const MIN: $bound, const MAX: $bound> Debug for OutOfBoundsError<MIN, MAX> {
+impl<const MIN: $bound, const MAX: $bound> Debug for $error<MIN, MAX> {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fmt.debug_struct("OutOfBoundsError")
+        fmt.debug_struct(stringify!($error))
             .field("value", &self.value())
             .field("min_allowed", &self.min_allowed())
             .field("max_allowed", &self.max_allowed())
@@ -220,14 +813,20 @@ impl<const MIN: $bound, const MAX: $bound> Debug for OutOfBoundsError<MIN, MAX>
     }
 }
 
-paste::paste!{
+impl<const MIN: $bound, const MAX: $bound> std::fmt::Display for $error<MIN, MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, concat!("value {} is out of bounds, allowed range is ", $range), self.value(), MIN, MAX)
+    }
+}
+
+impl<const MIN: $bound, const MAX: $bound> std::error::Error for $error<MIN, MAX> {}
+
 #[derive(Shrinkwrap, Debug, Copy, Clone)]
 #[derive(Serialize, Deserialize)]
-#[doc="An `" $int "` element that is forced to be within the inclusive range `MIN..=MAX`."]
+#[doc = concat!("An `", stringify!($int), "` element that is forced to be within ", $doc, ".")]
 pub struct $type<const MIN: $bound, const MAX: $bound>(
-    Result<$int, OutOfBoundsError<MIN, MAX>>,
+    Result<$int, $error<MIN, MAX>>,
 );
-}
 
 
 // /// A compound error type that stores result and errors of multiple operations between bounded values.
@@ -265,12 +864,47 @@ impl<const MIN: $bound, const MAX: $bound> $type<MIN, MAX> {
 
     /// Returns an out of bounds error after a failed conversion.
     fn out_of_bounds(val: $int) -> Self {
-        Self(Err(OutOfBoundsError::new(val)))
+        Self(Err($error::new(val)))
     }
 
     /// Function that returns whether a value is within the bounds.
     pub fn is_in_bounds(val: &impl PartialOrd<$int>) -> bool {
-        *val >= MIN && *val <= MAX
+        *val $lower MIN && *val $upper MAX
+    }
+
+    /// Returns the smallest and the largest allowed value, or `None` if no value is allowed.
+    const fn allowed_range() -> Option<($int, $int)> {
+        match ($first, $last) {
+            (Some(first), Some(last)) if first <= last => Some((first, last)),
+            _ => None,
+        }
+    }
+
+    /// Returns the allowed value that is closest to `val`. If no value is allowed, `val` is stored as out-of-bounds value.
+    fn clamp_to_bounds(val: $int) -> Self {
+        match Self::allowed_range() {
+            Some((first, last)) => Self(Ok(val.max(first).min(last))),
+            None => Self::out_of_bounds(val),
+        }
+    }
+
+    /// Converts the value into a bounded type with wider bounds. This only compiles if the new bounds contain the old bounds.
+    /// Out-of-bounds values are checked against the new bounds.
+    #[must_use]
+    pub fn widen<const NEW_MIN: $bound, const NEW_MAX: $bound>(self) -> $type<NEW_MIN, NEW_MAX> {
+        const { assert!(NEW_MIN <= MIN && MAX <= NEW_MAX, "the new bounds have to contain the old bounds") };
+        match self.0 {
+            Ok(val) => $type(Ok(val)),
+            Err(err) => $type::from(err.value()),
+        }
+    }
+
+    /// Converts the value into a bounded type with other (usually narrower) bounds.
+    /// # Errors
+    /// Fails if the value is outside of the new bounds.
+    pub fn narrow<const NEW_MIN: $bound, const NEW_MAX: $bound>(self) -> Result<$type<NEW_MIN, NEW_MAX>, $error<NEW_MIN, NEW_MAX>> {
+        let narrowed = $type::<NEW_MIN, NEW_MAX>::from(self.unchecked());
+        narrowed.0.map(|_| narrowed)
     }
 }
 
@@ -290,25 +924,11 @@ impl<const MIN: $bound, const MAX: $bound> FromStr for $type<MIN, MAX> {
     }
 }
 
-impl<const MIN: $bound, const MAX: $bound> PartialEq for $type<MIN, MAX> {
-    fn eq(&self, other: &Self) -> bool {
-        match (&self.0, &other.0) {
-            (Ok(self_val), Ok(other_val)) => self_val == other_val,
-            _ => false,
-        }
-    }
-}
-
 // Note: $type is not Eq because x != x for x.is_err()
 
-impl<const MIN: $bound, const MAX: $bound> PartialOrd for $type<MIN, MAX> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (&self.0, &other.0) {
-            (Ok(self_val), Ok(other_val)) => self_val.partial_cmp(other_val),
-            _ => None,
-        }
-    }
-}
+// allow for comparisons with any other bounded type, including this one with other bounds.
+derive_bounded_comparisons!($type, $bound; BoundedU8 u8, BoundedU16 u16, BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
+
 // // allowing for error-less conversion from Unbounded -> Bounded risks us ignoring errors, so we only allow try_into().
 // impl<const MIN: Bound, const MAX: Bound> TryFrom<Unbounded<UnboundedVal, $int, Bound>>
 //     for $type<MIN, MAX>
@@ -351,60 +971,118 @@ impl<const MIN: $bound, const MAX: $bound> PartialOrd for $type<MIN, MAX> {
 // allow for some operations and comparisons with regular integer types.
 derive_numeric_traits!($type, $bound, $int; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
+generate_strict!($type, $error, $bound, $int; $doc);
+derive_float_traits!($type, $bound, $int);
+derive_env!($type, $error, $bound, $int; $range);
+derive_arbitrary!($type, $bound, $int);
+derive_from_sql_error!($type, $bound, $int; $range);
+derive_rusqlite!($type, $bound, $int);
+derive_postgres!($type, $bound, $int);
+derive_clap!($type, $bound, $int; $help);
+derive_num_traits!($type, $bound, $int);
+derive_predicate!($type, $error, $bound, $int; $range);
+    };
+}
 
+/// Generates a bounded type with the specified type name, bound type and value type.
+macro_rules! generate_type {
+    ( $type: ident, $bound:ty, $int:ty )   => {
+        use derive_more::Constructor;
+        use shrinkwraprs::Shrinkwrap; //derives Deref, Borrow and AsRef
+        use std::cmp::Ordering;
+        use std::cmp::{PartialEq, PartialOrd};
+        use std::convert::TryFrom;
+        use std::fmt::Debug;
+        use std::str::FromStr;
+        use serde::{Serialize, Deserialize};
 
+generate_bounded!($type, OutOfBoundsError, $bound, $int; >=, <=, Some(MIN), Some(MAX), "{}..={}", "between {} and {}", "the inclusive range `MIN..=MAX`");
+generate_exclusive!($type, $bound, $int);
+generate_stepped!($type, $bound, $int);
+generate_union!($type, $bound, $int);
+generate_soft!($type, $bound, $int);
+generate_defaulted!($type, $bound, $int);
+derive_dyn_bounded!($type, $bound, $int);
+derive_any_bounded!($type, $bound, $int);
+derive_refined!($type, $bound, $int);
+derive_default!($type, $bound, $int);
     };
 }
 
 mod i8 {
     generate_type!(BoundedI8, i8, i8);
+    generate_one_sided!(BoundedI8, i8, i8; AtLeastI8, AtMostI8);
+    derive_bounded_conversions!(BoundedI8, i8; from ; try_from BoundedU8 u8, BoundedU16 u16, BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod i16 {
     generate_type!(BoundedI16, i16, i16);
+    generate_one_sided!(BoundedI16, i16, i16; AtLeastI16, AtMostI16);
+    derive_bounded_conversions!(BoundedI16, i16; from BoundedU8 u8, BoundedI8 i8; try_from BoundedU16 u16, BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod i32 {
     generate_type!(BoundedI32, i32, i32);
+    generate_one_sided!(BoundedI32, i32, i32; AtLeastI32, AtMostI32);
+    derive_bounded_conversions!(BoundedI32, i32; from BoundedU8 u8, BoundedU16 u16, BoundedI8 i8, BoundedI16 i16; try_from BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod i64 {
     generate_type!(BoundedI64, i64, i64);
+    generate_one_sided!(BoundedI64, i64, i64; AtLeastI64, AtMostI64);
+    derive_bounded_conversions!(BoundedI64, i64; from BoundedU8 u8, BoundedU16 u16, BoundedU32 u32, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32; try_from BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod i128 {
     generate_type!(BoundedI128, i128, i128);
+    generate_one_sided!(BoundedI128, i128, i128; AtLeastI128, AtMostI128);
+    derive_bounded_conversions!(BoundedI128, i128; from BoundedU8 u8, BoundedU16 u16, BoundedU32 u32, BoundedU64 u64, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64; try_from BoundedU128 u128, BoundedUsize usize, BoundedIsize isize);
 }
 
 mod isize {
     generate_type!(BoundedIsize, isize, isize);
+    generate_one_sided!(BoundedIsize, isize, isize; AtLeastIsize, AtMostIsize);
+    derive_bounded_conversions!(BoundedIsize, isize; from BoundedU8 u8, BoundedI8 i8, BoundedI16 i16; try_from BoundedU16 u16, BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128);
 }
 
 mod u8 {
     generate_type!(BoundedU8, u8, u8);
+    generate_one_sided!(BoundedU8, u8, u8; AtLeastU8, AtMostU8);
+    derive_bounded_conversions!(BoundedU8, u8; from ; try_from BoundedU16 u16, BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod u16 {
     generate_type!(BoundedU16, u16, u16);
+    generate_one_sided!(BoundedU16, u16, u16; AtLeastU16, AtMostU16);
+    derive_bounded_conversions!(BoundedU16, u16; from BoundedU8 u8; try_from BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod u32 {
     generate_type!(BoundedU32, u32, u32);
+    generate_one_sided!(BoundedU32, u32, u32; AtLeastU32, AtMostU32);
+    derive_bounded_conversions!(BoundedU32, u32; from BoundedU8 u8, BoundedU16 u16; try_from BoundedU64 u64, BoundedU128 u128, BoundedUsize usize, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod u64 {
     generate_type!(BoundedU64, u64, u64);
+    generate_one_sided!(BoundedU64, u64, u64; AtLeastU64, AtMostU64);
+    derive_bounded_conversions!(BoundedU64, u64; from BoundedU8 u8, BoundedU16 u16, BoundedU32 u32; try_from BoundedU128 u128, BoundedUsize usize, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod u128 {
     generate_type!(BoundedU128, u128, u128);
+    generate_one_sided!(BoundedU128, u128, u128; AtLeastU128, AtMostU128);
+    derive_bounded_conversions!(BoundedU128, u128; from BoundedU8 u8, BoundedU16 u16, BoundedU32 u32, BoundedU64 u64; try_from BoundedUsize usize, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 mod usize {
     generate_type!(BoundedUsize, usize, usize);
+    generate_one_sided!(BoundedUsize, usize, usize; AtLeastUsize, AtMostUsize);
+    derive_bounded_conversions!(BoundedUsize, usize; from BoundedU8 u8, BoundedU16 u16; try_from BoundedU32 u32, BoundedU64 u64, BoundedU128 u128, BoundedI8 i8, BoundedI16 i16, BoundedI32 i32, BoundedI64 i64, BoundedI128 i128, BoundedIsize isize);
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::i64::BoundedI64;
     use assert2::assert;
@@ -439,10 +1117,10 @@ mod tests {
     fn display_int() {
         let ok: BoundedI64<0, 10> = 5.into();
         let err: BoundedI64<0, 10> = 11.into();
-        assert_eq!(&format!("{}", ok), "5");
+        assert_eq!(&format!("{ok}"), "5");
         assert_eq!(
-            &format!("{}", err),
-            r#"OutOfBoundsError { value: 11, min_allowed: 0, max_allowed: 10 }"#
+            &format!("{err}"),
+            r"OutOfBoundsError { value: 11, min_allowed: 0, max_allowed: 10 }"
         );
     }
 
@@ -534,6 +1212,37 @@ mod tests {
         assert_eq!(5 < bounded_ok, false);
     }
 
+    #[test]
+    fn ordering_across_widths() {
+        use super::*;
+
+        let small: BoundedU8<0, 10> = 5.into();
+        assert!(small < 1000_u32);
+        assert!(small < u128::MAX);
+        assert!(small > -1_i64);
+        assert!(small > i128::MIN);
+        assert!(small != 1000_u32);
+        assert!(small != -1_i8);
+        assert!(1000_u32 > small);
+        assert!(-1_i64 < small);
+        assert!(small.partial_cmp(&261_u16) == Some(std::cmp::Ordering::Less));
+
+        let signed: BoundedI8<-10, 10> = (-5).into();
+        assert!(signed < 200_u8);
+        assert!(signed > -200_i16);
+        assert!(signed < u64::MAX);
+        assert!(signed.strict().unwrap() < u64::MAX);
+
+        let unsigned: BoundedU128<0, { u128::MAX }> = u128::MAX.into();
+        assert!(unsigned > -1_i8);
+        assert!(unsigned > i128::MAX);
+
+        // out-of-bounds values still can't be compared
+        let err: BoundedU8<0, 10> = 11.into();
+        assert_eq!(err < 1000_u32, false);
+        assert_eq!(err > -1_i64, false);
+    }
+
     #[test]
     fn ordering_err_literal() {
         let bounded_err: BoundedI64<2, 10> = 11.into();
@@ -602,6 +1311,147 @@ mod tests {
         assert!(!(bounded_err_2 == bounded_err_1));
     }
 
+    #[test]
+    fn compare_bounded_types() {
+        use super::*;
+
+        let narrow: BoundedI64<0, 10> = 5.into();
+        let wide: BoundedI64<0, 20> = 5.into();
+        let larger: BoundedI64<0, 20> = 15.into();
+        assert!(narrow == wide);
+        assert!(wide == narrow);
+        assert!(narrow < larger);
+        assert!(larger > narrow);
+
+        let small: BoundedU8<0, 255> = 200.into();
+        let negative: BoundedI32<-300, 0> = (-1).into();
+        let big: BoundedU128<0, { u128::MAX }> = u128::MAX.into();
+        assert!(small > negative);
+        assert!(negative < small);
+        assert!(small < big);
+        assert!(big > negative);
+        assert!(small == BoundedI16::<0, 1000>::from(200));
+
+        // out-of-bounds values are never equal or ordered, on either side
+        let err: BoundedI64<0, 10> = 15.into();
+        assert!(err != larger);
+        assert!(larger != err);
+        assert_eq!(err < larger, false);
+        assert_eq!(larger > err, false);
+        assert_eq!(small > err, false);
+    }
+
+    #[test]
+    fn widen_and_narrow() {
+        use super::*;
+
+        fn takes_wide(val: BoundedU16<0, 100>) -> u16 {
+            val.unchecked()
+        }
+
+        let narrow: BoundedU16<1, 10> = 7.into();
+        assert!(takes_wide(narrow.widen()) == 7);
+        assert!(narrow.widen::<1, 10>() == 7);
+        assert!(BoundedU16::<1, 10>::from(0).widen::<0, 100>().is_ok());
+        assert!(BoundedU16::<1, 10>::from(200).widen::<0, 100>().is_err());
+
+        let wide: BoundedU16<0, 100> = 50.into();
+        assert!(wide.narrow::<40, 60>().unwrap() == 50);
+        assert!(wide.narrow::<1, 10>().unwrap_err().value() == 50);
+        assert!(wide.narrow::<1, 10>().unwrap_err().max_allowed() == 10);
+
+        let strict = StrictBoundedU16::<1, 10>::try_from(7).unwrap();
+        assert!(strict.widen::<0, 100>() == 7);
+        assert!(strict.narrow::<5, 7>().unwrap() == 7);
+        assert!(strict.narrow::<1, 5>().is_err());
+    }
+
+    #[test]
+    fn convert_between_backing_types() {
+        use super::*;
+
+        let stored: BoundedU8<0, 100> = 42.into();
+        let computed: BoundedI32<0, 100> = stored.into();
+        assert!(computed == 42);
+        let narrower: BoundedI32<0, 10> = stored.into();
+        assert!(narrower.is_err());
+        assert!(narrower.unchecked() == 42);
+
+        let back = BoundedU8::<0, 100>::try_from(computed).unwrap();
+        assert!(back == 42);
+        let negative: BoundedI32<-10, 10> = (-5).into();
+        assert!(BoundedU8::<0, 100>::try_from(negative).unwrap_err().value() == 0);
+        assert!(BoundedU8::<0, 100>::try_from(BoundedI64::<0, 1000>::from(1000)).unwrap_err().value() == 255);
+
+        // the attempted value of an out-of-bounds value is carried across and checked against the new bounds
+        let err: BoundedU8<0, 10> = 200.into();
+        let wide: BoundedI64<-1000, 1000> = err.into();
+        assert!(wide == 200);
+        let still_err: BoundedI16<0, 100> = err.into();
+        assert!(still_err.unwrap_err().value() == 200);
+        assert!(BoundedU8::<0, 10>::from(-5).unwrap_err().value() == 0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_conversions() {
+        use super::*;
+
+        type Temperature = BoundedI16<-40, 125>;
+        assert!(Temperature::try_from_f64(21.5, Rounding::Floor).unwrap() == 21);
+        assert!(Temperature::try_from_f64(21.5, Rounding::Ceil).unwrap() == 22);
+        assert!(Temperature::try_from_f64(21.5, Rounding::HalfEven).unwrap() == 22);
+        assert!(Temperature::try_from_f64(22.5, Rounding::HalfEven).unwrap() == 22);
+        assert!(Temperature::try_from_f64(-3.7, Rounding::Truncate).unwrap() == -3);
+        assert!(Temperature::try_from_f64(-3.7, Rounding::Floor).unwrap() == -4);
+        assert!(Temperature::try_from_f64(125.4, Rounding::HalfEven).unwrap() == 125);
+        assert!(Temperature::try_from_f64(125.6, Rounding::HalfEven) == Err(FromFloatError::OutOfBounds(126.0)));
+        assert!(Temperature::try_from_f64(f64::NAN, Rounding::Floor) == Err(FromFloatError::NaN));
+        assert!(Temperature::try_from_f64(f64::INFINITY, Rounding::Floor).is_err());
+        assert!(Temperature::try_from_f64(1e300, Rounding::Floor).is_err());
+
+        assert!(Temperature::from_f64_clamped(300.0, Rounding::Floor).unwrap() == 125);
+        assert!(Temperature::from_f64_clamped(f64::NEG_INFINITY, Rounding::Floor).unwrap() == -40);
+        assert!(Temperature::from_f64_clamped(-39.5, Rounding::Floor).unwrap() == -40);
+        assert!(Temperature::from_f64_clamped(f64::NAN, Rounding::Floor).is_none());
+
+        let big = BoundedU64::<0, { u64::MAX }>::try_from_f64(1.8e19, Rounding::Floor).unwrap();
+        assert!(big == 18_000_000_000_000_000_000_u64);
+        assert!(BoundedU64::<0, { u64::MAX }>::try_from_f64(1.9e19, Rounding::Floor).is_err());
+
+        let strict = StrictBoundedI16::<-40, 125>::try_from(20).unwrap();
+        assert!(strict.to_f64() == 20.0);
+        assert!(Temperature::from(20).to_f64() == Some(20.0));
+        assert!(Temperature::from(200).to_f64().is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_comparisons() {
+        use super::*;
+
+        let bounded: BoundedI64<0, { i64::MAX }> = 5.into();
+        assert!(bounded == 5.0);
+        assert!(5.0 == bounded);
+        assert!(bounded != 5.5);
+        assert!(bounded < 5.5);
+        assert!(bounded > 4.5);
+        assert!(bounded > -1e300);
+        assert!(bounded < f64::INFINITY);
+        assert!(4.9 < bounded);
+        assert!(bounded.partial_cmp(&f64::NAN) == None);
+        assert_eq!(bounded.eq(&f64::NAN), false);
+
+        // i64::MAX can't be represented as f64, but is still compared exactly
+        let max: BoundedI64<0, { i64::MAX }> = i64::MAX.into();
+        assert!(max < 9_223_372_036_854_775_808.0);
+        assert!(max != 9_223_372_036_854_775_808.0);
+
+        let err: BoundedI64<0, 10> = 11.into();
+        assert_eq!(err == 11.0, false);
+        assert_eq!(err > 0.0, false);
+    }
+
     #[test]
     fn from_str() {
         use std::str::FromStr;
@@ -614,6 +1464,1064 @@ mod tests {
         assert!(parsed_out_of_bounds.is_err());
     }
 
+    #[test]
+    fn from_env() {
+        std::env::set_var("BOUNDED_TYPES_TEST_OK", "8");
+        std::env::set_var("BOUNDED_TYPES_TEST_OUT_OF_BOUNDS", "100");
+        std::env::set_var("BOUNDED_TYPES_TEST_INVALID", "many");
+        std::env::remove_var("BOUNDED_TYPES_TEST_MISSING");
+
+        assert!(BoundedI64::<1, 64>::from_env("BOUNDED_TYPES_TEST_OK").unwrap() == 8);
+        assert!(BoundedI64::<1, 64>::from_env_or("BOUNDED_TYPES_TEST_OK", 4).unwrap() == 8);
+        assert!(BoundedI64::<1, 64>::from_env_or("BOUNDED_TYPES_TEST_MISSING", 4).unwrap() == 4);
+
+        let missing = BoundedI64::<1, 64>::from_env("BOUNDED_TYPES_TEST_MISSING").unwrap_err();
+        assert!(missing.is_not_present());
+        assert!(missing.value() == None);
+
+        let out_of_bounds = BoundedI64::<1, 64>::from_env_or("BOUNDED_TYPES_TEST_OUT_OF_BOUNDS", 4).unwrap_err();
+        assert!(out_of_bounds.variable() == "BOUNDED_TYPES_TEST_OUT_OF_BOUNDS");
+        assert!(out_of_bounds.value() == Some("100"));
+        assert!(out_of_bounds.out_of_bounds().unwrap().value() == 100);
+        assert!(
+            out_of_bounds.to_string()
+                == r#"environment variable BOUNDED_TYPES_TEST_OUT_OF_BOUNDS="100" is out of bounds, allowed range is 1..=64"#
+        );
+
+        let invalid = BoundedI64::<1, 64>::from_env("BOUNDED_TYPES_TEST_INVALID").unwrap_err();
+        assert!(invalid.value() == Some("many"));
+        assert!(invalid.out_of_bounds().is_none());
+        assert!(invalid.to_string().ends_with("allowed range is 1..=64"));
+
+        let bad_default: crate::BoundedI64FromEnvError<1, 64> =
+            BoundedI64::<1, 64>::from_env_or("BOUNDED_TYPES_TEST_MISSING", 100).unwrap_err();
+        assert!(!bad_default.is_not_present());
+        assert!(bad_default.out_of_bounds().is_none());
+        assert!(bad_default.default_out_of_bounds().unwrap().value() == 100);
+        assert!(
+            bad_default.to_string()
+                == "environment variable BOUNDED_TYPES_TEST_MISSING is not set and the default value 100 is out of bounds, allowed range is 1..=64"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary_in_bounds() {
+        use super::*;
+        use arbitrary::{Arbitrary, Unstructured};
+
+        /// Returns how many bytes generating a `T` consumes, in the format of a size hint.
+        fn consumed<T: for<'a> Arbitrary<'a>>() -> (usize, Option<usize>) {
+            let data = [7; 32];
+            let mut u = Unstructured::new(&data);
+            T::arbitrary(&mut u).unwrap();
+            let bytes = data.len() - u.len();
+            (bytes, Some(bytes))
+        }
+
+        for byte in 0..=u8::MAX {
+            let data = [byte; 16];
+            let signed = BoundedI8::<-3, 5>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            let unsigned = BoundedU8::<250, 255>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            let wide = BoundedI128::<{ i128::MIN }, { i128::MAX }>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            let narrow = BoundedU64::<7, 7>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert!(signed.is_ok());
+            assert!(unsigned.is_ok());
+            assert!(wide.is_ok());
+            assert!(narrow == 7);
+            assert!(StrictBoundedI8::<-3, 5>::arbitrary(&mut Unstructured::new(&data)).is_ok());
+        }
+
+        // every value of the range is reachable
+        let reached: std::collections::BTreeSet<i8> = (0..=u8::MAX)
+            .map(|byte| BoundedI8::<-3, 5>::arbitrary(&mut Unstructured::new(&[byte])).unwrap().unchecked())
+            .collect();
+        assert!(reached == (-3..=5).collect());
+
+        assert!(BoundedI32::<5, 1>::arbitrary(&mut Unstructured::new(&[0; 4])).is_err());
+        assert!(ExclusiveBoundedI32::<0, 1>::arbitrary(&mut Unstructured::new(&[0; 4])).is_err());
+
+        // only values within the exclusive bounds are generated
+        let reached: std::collections::BTreeSet<u8> = (0..=u8::MAX)
+            .map(|byte| ExclusiveBoundedU8::<0, 4>::arbitrary(&mut Unstructured::new(&[byte])).unwrap().unchecked())
+            .collect();
+        assert!(reached == (1..4).collect());
+        assert!(StrictHalfOpenBoundedU16::<0, 256>::arbitrary(&mut Unstructured::new(&[255; 2])).unwrap() < 256);
+
+        // the size hint is exactly the number of bytes that are consumed
+        assert!(BoundedU16::<0, 10>::size_hint(0) == (1, Some(1)));
+        assert!(BoundedU16::<0, 255>::size_hint(0) == (1, Some(1)));
+        assert!(BoundedU16::<0, 256>::size_hint(0) == (2, Some(2)));
+        assert!(BoundedI8::<-128, 127>::size_hint(0) == (1, Some(1)));
+        assert!(BoundedI128::<0, 10>::size_hint(0) == (1, Some(1)));
+        assert!(BoundedI128::<{ i128::MIN }, { i128::MAX }>::size_hint(0) == (16, Some(16)));
+        assert!(StrictBoundedU64::<7, 7>::size_hint(0) == (0, Some(0)));
+        assert!(BoundedI32::<5, 1>::size_hint(0) == (0, Some(0)));
+        assert!(BoundedU16::<0, 256>::size_hint(0) == consumed::<BoundedU16<0, 256>>());
+        assert!(BoundedI64::<-3, 5>::size_hint(0) == consumed::<BoundedI64<-3, 5>>());
+        assert!(BoundedU32::<0, { u32::MAX }>::size_hint(0) == consumed::<BoundedU32<0, { u32::MAX }>>());
+        assert!(StrictBoundedU64::<7, 7>::size_hint(0) == consumed::<StrictBoundedU64<7, 7>>());
+        assert!(HalfOpenBoundedU16::<0, 256>::size_hint(0) == (1, Some(1)));
+        assert!(ExclusiveBoundedU16::<0, 2>::size_hint(0) == (0, Some(0)));
+        assert!(ExclusiveBoundedU16::<0, 1>::size_hint(0) == (0, Some(0)));
+    }
+
+    #[test]
+    #[cfg(feature = "rusqlite")]
+    fn rusqlite_round_trip() {
+        use super::*;
+        use rusqlite::Connection;
+
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE t (id INTEGER, val INTEGER)", []).unwrap();
+        let ok: BoundedU8<1, 10> = 7.into();
+        let err: BoundedU8<1, 10> = 11.into();
+        conn.execute("INSERT INTO t VALUES (1, ?1)", [ok]).unwrap();
+        assert!(conn.execute("INSERT INTO t VALUES (2, ?1)", [err]).is_err());
+        conn.execute("INSERT INTO t VALUES (3, 20), (4, 300)", []).unwrap();
+
+        let read = |id: i64| conn.query_row("SELECT val FROM t WHERE id = ?1", [id], |row| row.get::<_, BoundedU8<1, 10>>(0));
+        assert!(read(1).unwrap() == 7);
+        // values that don't fit into the backing integer fail with the same error type
+        let sql_error = |err: rusqlite::Error| match err {
+            rusqlite::Error::FromSqlConversionFailure(_, _, err) => *err.downcast::<BoundedU8FromSqlError<1, 10>>().unwrap(),
+            err => panic!("unexpected error {}", err),
+        };
+        let out_of_bounds = sql_error(read(3).unwrap_err());
+        assert!(out_of_bounds.value() == 20);
+        assert!(out_of_bounds.to_string() == "value 20 is out of bounds, allowed range is 1..=10");
+        let overflow = sql_error(read(4).unwrap_err());
+        assert!(overflow.value() == 300);
+        assert!(overflow.max_allowed() == 10);
+        assert!(overflow.to_string() == "value 300 is out of bounds, allowed range is 1..=10");
+        let wide = conn.query_row("SELECT 1099511627776", [], |row| row.get::<_, BoundedI32<-5, 5>>(0));
+        assert!(wide.unwrap_err().to_string().contains("value 1099511627776 is out of bounds, allowed range is -5..=5"));
+
+        let half_open = |id: i64| conn.query_row("SELECT val FROM t WHERE id = ?1", [id], |row| row.get::<_, HalfOpenBoundedU8<1, 8>>(0));
+        assert!(half_open(1).unwrap() == 7);
+        let err = match half_open(3).unwrap_err() {
+            rusqlite::Error::FromSqlConversionFailure(_, _, err) => *err.downcast::<HalfOpenBoundedU8FromSqlError<1, 8>>().unwrap(),
+            err => panic!("unexpected error {}", err),
+        };
+        assert!(err.to_string() == "value 20 is out of bounds, allowed range is 1..8");
+        assert!(conn.execute("INSERT INTO t VALUES (5, ?1)", [ExclusiveBoundedU8::<1, 10>::from(1)]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "postgres")]
+    fn postgres_round_trip() {
+        use super::*;
+        use bytes::BytesMut;
+        use postgres_types::{FromSql, ToSql, Type};
+
+        let ok: BoundedI64<-5, 300> = 300.into();
+        let err: BoundedI64<-5, 300> = 301.into();
+
+        for ty in &[Type::INT2, Type::INT4, Type::INT8] {
+            let mut buf = BytesMut::new();
+            ok.to_sql_checked(ty, &mut buf).unwrap();
+            assert!(BoundedI64::<-5, 300>::from_sql(ty, &buf).unwrap() == 300);
+            let read_err = BoundedI64::<-5, 100>::from_sql(ty, &buf).unwrap_err();
+            assert!(read_err.downcast::<BoundedI64FromSqlError<-5, 100>>().unwrap().value() == 300);
+            assert!(err.to_sql_checked(ty, &mut BytesMut::new()).is_err());
+        }
+
+        let mut buf = BytesMut::new();
+        ok.to_sql_checked(&Type::INT4, &mut buf).unwrap();
+        let overflow = BoundedU8::<0, 255>::from_sql(&Type::INT4, &buf).unwrap_err();
+        assert!(overflow.to_string() == "value 300 is out of bounds, allowed range is 0..=255");
+        assert!(overflow.downcast::<BoundedU8FromSqlError<0, 255>>().unwrap().value() == 300);
+
+        let mut buf = BytesMut::new();
+        (1_i64 << 40).to_sql_checked(&Type::INT8, &mut buf).unwrap();
+        let overflow = BoundedI32::<-5, 5>::from_sql(&Type::INT8, &buf).unwrap_err().to_string();
+        assert!(overflow == "value 1099511627776 is out of bounds, allowed range is -5..=5");
+        assert!(ok.to_sql_checked(&Type::TEXT, &mut BytesMut::new()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "clap")]
+    fn clap_value_parser() {
+        use super::*;
+        use clap::{value_parser, Arg, Command};
+
+        let mut cmd = Command::new("server").arg(
+            Arg::new("port")
+                .long("port")
+                .help(format!("Port to listen on, {}", BoundedU16::<1, 65535>::range_help()))
+                .value_parser(value_parser!(BoundedU16<1, 65535>)),
+        );
+        let port = |arg: &str| cmd.clone().try_get_matches_from(["server".to_owned(), format!("--port={arg}")]);
+
+        let matches = port("8080").unwrap();
+        assert!(*matches.get_one::<BoundedU16<1, 65535>>("port").unwrap() == 8080);
+        assert!(port("0").unwrap_err().to_string().contains("must be between 1 and 65535"));
+        assert!(port("70000").unwrap_err().to_string().contains("must be between 1 and 65535"));
+        assert!(port("-1").unwrap_err().to_string().contains("must be between 1 and 65535"));
+        assert!(port("http").unwrap_err().to_string().contains("invalid digit"));
+        assert!(cmd.render_help().to_string().contains("--port <port>  Port to listen on, between 1 and 65535"));
+        let offset = |arg: &str| {
+            Command::new("seek")
+                .arg(Arg::new("offset").long("offset").value_parser(value_parser!(HalfOpenBoundedUsize<0, 512>)))
+                .try_get_matches_from(["seek".to_owned(), format!("--offset={arg}")])
+        };
+        assert!(*offset("511").unwrap().get_one::<HalfOpenBoundedUsize<0, 512>>("offset").unwrap() == 511);
+        assert!(offset("512").unwrap_err().to_string().contains("must be at least 0 and less than 512"));
+        assert!(ExclusiveBoundedI8::<0, 10>::range_help() == "greater than 0 and less than 10");
+        // the range isn't offered as a possible value, so shell completions don't suggest it
+        assert!(cmd.get_arguments().all(|arg| arg.get_possible_values().is_empty()));
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn num_traits() {
+        use super::*;
+        use num_traits::{
+            Bounded, CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, NumCast, SaturatingAdd, SaturatingMul,
+            SaturatingSub, ToPrimitive,
+        };
+
+        fn sum<T: CheckedAdd + Bounded + Copy>(values: &[T]) -> Option<T> {
+            values.iter().try_fold(T::min_value(), |acc, val| acc.checked_add(val))
+        }
+
+        let a: BoundedI32<0, 100> = 60.into();
+        let b: BoundedI32<0, 100> = 50.into();
+        let err: BoundedI32<0, 100> = 101.into();
+
+        assert!(BoundedI32::<0, 100>::min_value() == 0);
+        assert!(BoundedI32::<0, 100>::max_value() == 100);
+        assert!(sum(&[b, b]).unwrap() == 100);
+        assert!(sum(&[a, b]).is_none());
+
+        assert!(a.checked_add(&b).is_none());
+        assert!(a.checked_sub(&b).unwrap() == 10);
+        assert!(b.checked_sub(&a).is_none());
+        assert!(a.checked_mul(&err).is_none());
+        assert!(a.saturating_add(&b) == 100);
+        assert!(b.saturating_sub(&a) == 0);
+        assert!(a.saturating_mul(&b) == 100);
+        assert!(a.saturating_add(&err).is_err());
+
+        assert!((a + b).is_err());
+        assert!((a + b).unchecked() == 110);
+        assert!(a - b == 10);
+        assert!((a - err).is_err());
+        let large: BoundedU8<0, 255> = 200.into();
+        let small: BoundedU8<0, 100> = 15.into();
+        assert!((small * small).is_err());
+        assert!((small * small).unchecked() == 225);
+        // results that overflow the backing integer are out of bounds, even if the saturated value isn't
+        assert!((large * large).is_err());
+        assert!((large * large).unchecked() == 255);
+        let zero: BoundedU8<0, 255> = 0.into();
+        assert!((zero - large).is_err());
+        assert!((zero - large).unchecked() == 0);
+
+        assert!(a.to_u8() == Some(60));
+        assert!(a.to_f64() == Some(60.0));
+        assert!(err.to_i64().is_none());
+        assert!(BoundedI32::<0, 100>::from_u64(100).unwrap() == 100);
+        assert!(BoundedI32::<0, 100>::from_i8(-1).is_none());
+        assert!(BoundedI32::<0, 100>::from_f64(99.5).unwrap() == 99);
+        assert!(<BoundedI32<0, 100> as NumCast>::from(7_u128).unwrap() == 7);
+        assert!(<BoundedI32<0, 100> as NumCast>::from(700_u128).is_none());
+
+        // the exclusive types saturate at their smallest and largest allowed value
+        let c: HalfOpenBoundedI32<0, 100> = 60.into();
+        let d: ExclusiveBoundedI32<0, 100> = 60.into();
+        assert!(HalfOpenBoundedI32::<0, 100>::max_value() == 99);
+        assert!(ExclusiveBoundedI32::<0, 100>::min_value() == 1);
+        assert!(c.saturating_add(&c) == 99);
+        assert!(d.saturating_sub(&d) == 1);
+        assert!(c.checked_add(&c).is_none());
+        assert!((c + c).unchecked() == 120);
+        assert!(ExclusiveBoundedI32::<0, 100>::from_i64(100).is_none());
+    }
+
+    #[test]
+    fn strict_total_order() {
+        use super::*;
+        use std::collections::{BTreeMap, HashSet};
+
+        let lenient: BoundedU8<0, 10> = 4.into();
+        let strict = lenient.strict().unwrap();
+        assert!(strict == 4);
+        assert!(4 == strict);
+        assert!(strict < 10);
+        assert!(strict.get() == 4);
+        assert!(BoundedU8::from(strict) == lenient);
+        assert!(BoundedU8::<0, 10>::from(11).strict().unwrap_err().value() == 11);
+        assert!(StrictBoundedU8::<0, 10>::try_from(-1).is_err());
+        assert!(StrictBoundedU8::<0, 10>::try_from(1000_u64).is_err());
+
+        let mut values: Vec<StrictBoundedI32<-5, 5>> = [3, -5, 0, 3]
+            .iter()
+            .map(|&val| StrictBoundedI32::try_from(val).unwrap())
+            .collect();
+        values.sort();
+        assert!(values == [-5, 0, 3, 3]);
+        assert!(values.iter().collect::<HashSet<_>>().len() == 3);
+
+        let mut map = BTreeMap::new();
+        map.insert(values[3], "three");
+        assert!(map[&StrictBoundedI32::try_from(3).unwrap()] == "three");
+        assert!(format!("{}", values[0]) == "-5");
+    }
+
+    #[test]
+    fn strict_to_primitive() {
+        use super::*;
+
+        let byte = StrictBoundedI64::<0, 255>::try_from(200).unwrap();
+        assert!(byte.to_u8() == 200_u8);
+        assert!(byte.to_i16() == 200_i16);
+        assert!(byte.to_u128() == 200_u128);
+
+        let signed = StrictBoundedI64::<-128, 127>::try_from(-100).unwrap();
+        assert!(signed.to_i8() == -100_i8);
+
+        let huge = StrictBoundedU128::<{ u128::MAX - 1 }, { u128::MAX }>::try_from(u128::MAX).unwrap();
+        assert!(huge.to_u128() == u128::MAX);
+        let small = StrictBoundedU128::<0, 100>::try_from(100).unwrap();
+        assert!(small.to_i8() == 100_i8);
+    }
+
+    #[test]
+    fn strict_serde() {
+        use super::*;
+        use serde::de::value::{Error, I64Deserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let deserializer: I64Deserializer<Error> = 7_i64.into_deserializer();
+        assert!(StrictBoundedI64::<0, 10>::deserialize(deserializer).unwrap() == 7);
+        let deserializer: I64Deserializer<Error> = 11_i64.into_deserializer();
+        assert!(StrictBoundedI64::<0, 10>::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn bounded_f64() {
+        use super::*;
+        use serde::de::value::{Error, F64Deserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+        use std::collections::HashSet;
+
+        type Probability = BoundedF64<0, 1, 1>;
+        type Gain = BoundedF64<-1, 1, 1>;
+        type Percentage = BoundedF64<0, 100, 1>;
+
+        assert!(Probability::MIN == 0.0);
+        assert!(BoundedF64::<-5, 5, 10>::MAX == 0.5);
+        assert!(Probability::from(0.25) == 0.25);
+        assert!(Probability::from(1.0).is_ok());
+        assert!(Probability::from(1.01).is_err());
+        assert!(Probability::from(f64::NAN).is_err());
+        assert!(Gain::from(f64::NEG_INFINITY).is_err());
+        assert!(Gain::from(-1.0) < 0.0);
+        assert!(0.5 > Gain::from(-0.5));
+        assert!(Percentage::from(50.0) > Probability::from(0.5));
+        assert!(Percentage::from(0.5) == Probability::from(0.5));
+
+        let err = Probability::from(1.5);
+        assert_eq!(err == 1.5, false);
+        assert_eq!(err > 0.0, false);
+        assert!(err.unchecked() == 1.5);
+        assert!(err.to_string().contains("value: 1.5"));
+        let strict_err: FloatOutOfBoundsError<0, 1, 1> = err.strict().unwrap_err();
+        assert!(strict_err.to_string() == "value 1.5 is out of bounds, allowed range is 0..=1");
+        assert!(strict_err.max_allowed() == 1.0);
+
+        assert!(Gain::from_f64_clamped(3.0).unwrap() == 1.0);
+        assert!(Gain::from_f64_clamped(f64::NEG_INFINITY).unwrap() == -1.0);
+        assert!(Gain::from_f64_clamped(f64::NAN).is_none());
+        assert!("0.75".parse::<Probability>().unwrap() == 0.75);
+        assert!("-0.75".parse::<Probability>().unwrap().is_err());
+        assert!(Probability::from(0.75).to_string() == "0.75");
+
+        let strict = StrictBoundedF64::<-1, 1, 1>::try_from(-0.0).unwrap();
+        assert!(strict.get().is_sign_positive());
+        assert!(StrictBoundedF64::<-1, 1, 1>::try_from(f64::NAN).is_err());
+        assert!(StrictBoundedF64::<-1, 1, 1>::from_f64_clamped(-2.0).unwrap() == -1.0);
+        let set: HashSet<StrictBoundedF64<-1, 1, 1>> = vec![strict, StrictBoundedF64::try_from(0.0).unwrap()].into_iter().collect();
+        assert!(set.len() == 1);
+        let mut sorted = [Gain::from(0.5).strict().unwrap(), Gain::from(-0.5).strict().unwrap()];
+        sorted.sort();
+        assert!(sorted[0] == -0.5);
+
+        let deserializer: F64Deserializer<Error> = 0.5_f64.into_deserializer();
+        assert!(StrictBoundedF64::<0, 1, 1>::deserialize(deserializer).unwrap() == 0.5);
+        let deserializer: F64Deserializer<Error> = f64::NAN.into_deserializer();
+        assert!(StrictBoundedF64::<0, 1, 1>::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    fn bounded_char() {
+        use super::*;
+        use serde::de::value::{CharDeserializer, Error};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+        use std::collections::BTreeSet;
+
+        type Lowercase = BoundedChar<'a', 'z'>;
+
+        assert!(Lowercase::from('q') == 'q');
+        assert!(Lowercase::from('A').is_err());
+        assert!(Lowercase::from('q') > 'a');
+        assert!('z' >= Lowercase::from('q'));
+        assert!(Lowercase::from('q') == BoundedChar::<'a', 'q'>::from('q'));
+        assert!("m".parse::<Lowercase>().unwrap() == 'm');
+        assert!("mm".parse::<Lowercase>().is_err());
+        assert!("M".parse::<Lowercase>().unwrap().is_err());
+        assert!(Lowercase::from('m').to_string() == "m");
+
+        let err = Lowercase::from('!');
+        assert_eq!(err == '!', false);
+        assert_eq!(err < 'a', false);
+        assert!(err.unchecked() == '!');
+        let strict_err: CharOutOfBoundsError<'a', 'z'> = err.strict().unwrap_err();
+        assert!(strict_err.to_string() == "value '!' is out of bounds, allowed range is 'a'..='z'");
+        assert!(strict_err.min_allowed() == 'a');
+
+        let code_point: BoundedU32<0, 127> = Lowercase::from('a').into();
+        assert!(code_point == 97);
+        let code_point: BoundedU32<0, 127> = BoundedChar::<'a', 'ä'>::from('ä').into();
+        assert!(code_point.is_err());
+
+        assert!(Lowercase::values().count() == 26);
+        assert!(Lowercase::values().all(|c| c.is_ok()));
+        assert!(StrictBoundedChar::<'\u{d7ff}', '\u{e000}'>::values().count() == 2);
+        let set: BTreeSet<StrictBoundedChar<'a', 'c'>> = StrictBoundedChar::values().rev().collect();
+        assert!(set.iter().next().unwrap() == &'a');
+
+        assert!(StrictBoundedChar::<'a', 'z'>::try_from('b').unwrap().get() == 'b');
+        assert!(StrictBoundedChar::<'a', 'z'>::try_from('B').is_err());
+        let deserializer: CharDeserializer<Error> = 'x'.into_deserializer();
+        assert!(StrictBoundedChar::<'a', 'z'>::deserialize(deserializer).unwrap() == 'x');
+        let deserializer: CharDeserializer<Error> = 'X'.into_deserializer();
+        assert!(StrictBoundedChar::<'a', 'z'>::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    fn bounded_duration() {
+        use super::*;
+        use serde::de::value::{Error, StrDeserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+        use std::time::Duration;
+
+        type Timeout = BoundedDuration<100, 5000>;
+
+        assert!(Timeout::MIN == Duration::from_millis(100));
+        assert!(Timeout::from(Duration::from_secs(1)) == Duration::from_secs(1));
+        assert!(Timeout::from(Duration::from_millis(99)).is_err());
+        assert!(Timeout::from(Duration::from_secs(1)) < Duration::from_secs(2));
+        assert!(Duration::from_secs(6) > Timeout::from(Duration::from_secs(5)));
+        assert!(Timeout::from(Duration::from_secs(1)) == BoundedDuration::<0, 1000>::from(Duration::from_secs(1)));
+
+        assert!("250ms".parse::<Timeout>().unwrap() == Duration::from_millis(250));
+        assert!("5s".parse::<Timeout>().unwrap() == Duration::from_secs(5));
+        assert!("1m".parse::<Timeout>().unwrap().unchecked() == Duration::from_secs(60));
+        assert!("1m".parse::<Timeout>().unwrap().is_err());
+        assert!("250000 us".parse::<Timeout>().unwrap() == Duration::from_millis(250));
+        assert!("250".parse::<Timeout>().unwrap_err() == ParseDurationError::UnknownUnit);
+        assert!("ms".parse::<Timeout>().unwrap_err() == ParseDurationError::InvalidNumber);
+        assert!("-5s".parse::<Timeout>().unwrap_err() == ParseDurationError::InvalidNumber);
+        assert!("18446744073709551615h".parse::<Timeout>().unwrap_err() == ParseDurationError::Overflow);
+
+        assert!(Timeout::from(Duration::from_millis(250)).to_string() == "250ms");
+        assert!(Timeout::from(Duration::from_micros(150_500)).to_string() == "150500us");
+        assert!(Timeout::from(Duration::from_secs(2)).to_string() == "2s");
+        let strict_err: DurationOutOfBoundsError<100, 5000> = Timeout::from(Duration::from_secs(6)).strict().unwrap_err();
+        assert!(strict_err.to_string() == "value 6s is out of bounds, allowed range is 100ms..=5s");
+
+        assert!(Timeout::from_duration_clamped(Duration::ZERO) == Duration::from_millis(100));
+        assert!(Timeout::from_duration_clamped(Duration::MAX) == Duration::from_secs(5));
+        let timeout = Timeout::from(Duration::from_secs(4));
+        assert!(timeout.saturating_add(Duration::from_secs(2)) == Duration::from_secs(5));
+        assert!(timeout.saturating_add(Duration::MAX) == Duration::from_secs(5));
+        assert!(timeout.saturating_sub(Duration::from_secs(10)) == Duration::from_millis(100));
+        assert!(timeout.saturating_sub(Duration::from_secs(1)) == Duration::from_secs(3));
+        assert!(Timeout::from(Duration::ZERO).saturating_add(Duration::from_millis(1)).is_err());
+        assert!(BoundedDuration::<5000, 100>::from_duration_clamped(Duration::from_secs(1)).is_err());
+
+        let strict = StrictBoundedDuration::<100, 5000>::try_from(Duration::from_secs(4)).unwrap();
+        assert!(strict.saturating_add(Duration::from_secs(2)) == Duration::from_secs(5));
+        assert!(strict.get() == Duration::from_secs(4));
+        let deserializer: StrDeserializer<'_, Error> = "250ms".into_deserializer();
+        assert!(StrictBoundedDuration::<100, 5000>::deserialize(deserializer).unwrap() == Duration::from_millis(250));
+        let deserializer: StrDeserializer<'_, Error> = "50ms".into_deserializer();
+        assert!(StrictBoundedDuration::<100, 5000>::deserialize(deserializer).is_err());
+        let deserializer: StrDeserializer<'_, Error> = "5 parsecs".into_deserializer();
+        assert!(StrictBoundedDuration::<100, 5000>::deserialize(deserializer).is_err());
+        let deserializer: StrDeserializer<'_, Error> = "250ms".into_deserializer();
+        assert!(Timeout::deserialize(deserializer).unwrap() == Duration::from_millis(250));
+        let deserializer: StrDeserializer<'_, Error> = "50ms".into_deserializer();
+        assert!(Timeout::deserialize(deserializer).unwrap().unchecked() == Duration::from_millis(50));
+        let deserializer: StrDeserializer<'_, Error> = "5 parsecs".into_deserializer();
+        assert!(Timeout::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    fn exclusive_bounds() {
+        use super::*;
+
+        const N: usize = 4;
+        let offsets: Vec<HalfOpenBoundedUsize<0, N>> = (0..=N).map(HalfOpenBoundedUsize::from).collect();
+        assert!(offsets[0] == 0);
+        assert!(offsets[N - 1] == N - 1);
+        assert!(offsets[N].is_err());
+        assert!(HalfOpenBoundedI8::<-5, 5>::from(-5) == -5);
+        assert!(HalfOpenBoundedI8::<-5, 5>::from(i128::MAX).is_err());
+        assert!(HalfOpenBoundedI8::<-5, 5>::from(4) < 5);
+        assert!(ExclusiveBoundedI64::<0, 10>::from(0).is_err());
+        assert!(ExclusiveBoundedI64::<0, 10>::from(10).is_err());
+        assert!(ExclusiveBoundedI64::<0, 10>::from(1) == 1);
+        assert!(ExclusiveBoundedI64::<0, 10>::from(9) > 8_u8);
+        assert!("3".parse::<ExclusiveBoundedU8<0, 4>>().unwrap() == 3);
+        assert!("4".parse::<ExclusiveBoundedU8<0, 4>>().unwrap().is_err());
+
+        let err = HalfOpenBoundedU16::<0, 10>::from(10);
+        assert_eq!(err == 10, false);
+        assert!(err.unchecked() == 10);
+        assert!(err.unwrap_err().to_string() == "value 10 is out of bounds, allowed range is 0..10");
+        let err = ExclusiveBoundedU16::<0, 10>::from(0);
+        assert!(err.unwrap_err().to_string() == "value 0 is out of bounds, allowed range is 0..10 excluding both bounds");
+        let err: HalfOpenBoundedU16OutOfBoundsError<0, 10> = HalfOpenBoundedU16::<0, 10>::from(10).unwrap_err();
+        assert!(format!("{:?}", err) == "HalfOpenBoundedU16OutOfBoundsError { value: 10, min_allowed: 0, max_allowed: 10 }");
+        let err: ExclusiveBoundedU16OutOfBoundsError<0, 10> = ExclusiveBoundedU16::<0, 10>::from(0).unwrap_err();
+        assert!(err.value() == 0);
+
+        // conversions check the value against the new bounds
+        let inclusive: BoundedUsize<0, 3> = offsets[3].into();
+        assert!(inclusive == 3);
+        let half_open: HalfOpenBoundedUsize<0, 3> = inclusive.into();
+        assert!(half_open.is_err());
+        let exclusive: ExclusiveBoundedUsize<0, 4> = offsets[0].into();
+        assert!(exclusive.is_err());
+        let half_open: HalfOpenBoundedUsize<0, 4> = ExclusiveBoundedUsize::<0, 4>::from(2).into();
+        assert!(half_open == 2);
+
+        assert!(HalfOpenBoundedI32::<0, 10>::from(5) == BoundedU8::<0, 5>::from(5));
+        assert!(ExclusiveBoundedI32::<0, 10>::from(5) < BoundedI64::<0, 10>::from(6));
+
+        // comparisons across bounds, with each other and with the inclusive type of the same backing integer
+        assert!(HalfOpenBoundedI32::<0, 10>::from(5) == HalfOpenBoundedI32::<5, 6>::from(5));
+        assert!(HalfOpenBoundedI32::<0, 10>::from(5) < HalfOpenBoundedI32::<0, 100>::from(50));
+        assert!(ExclusiveBoundedI32::<0, 10>::from(5) == HalfOpenBoundedI32::<0, 10>::from(5));
+        assert!(HalfOpenBoundedI32::<0, 10>::from(5) > ExclusiveBoundedI32::<0, 10>::from(4));
+        assert!(BoundedI32::<0, 10>::from(5) == ExclusiveBoundedI32::<0, 10>::from(5));
+        assert!(BoundedI32::<0, 10>::from(10) > HalfOpenBoundedI32::<0, 10>::from(9));
+        let err = HalfOpenBoundedI32::<0, 10>::from(10);
+        assert_eq!(err == HalfOpenBoundedI32::<0, 100>::from(10), false);
+        assert_eq!(BoundedI32::<0, 10>::from(10) == err, false);
+        assert_eq!(ExclusiveBoundedI32::<0, 10>::from(5).partial_cmp(&err), None);
+
+        // the same strict variants, widening and conversions between backing integers as the inclusive types
+        let strict: StrictHalfOpenBoundedUsize<0, N> = offsets[1].strict().unwrap();
+        let set: std::collections::BTreeSet<_> = offsets.iter().filter_map(|offset| offset.strict().ok()).collect();
+        assert!(set.len() == N);
+        assert!(StrictExclusiveBoundedI8::<0, 10>::try_from(0).is_err());
+        assert!(StrictExclusiveBoundedI8::<0, 10>::try_from(9).unwrap() == 9);
+        assert!(strict.widen::<0, 10>() == 1);
+        assert!(offsets[3].narrow::<0, 3>().is_err());
+        assert!(offsets[2].narrow::<2, 3>().unwrap() == 2);
+        assert!(ExclusiveBoundedU16::<0, 256>::from(255).strict().unwrap().to_u8() == 255);
+        let widened: HalfOpenBoundedI64<-1, 300> = HalfOpenBoundedU8::<0, 255>::from(254).into();
+        assert!(widened == 254);
+        assert!(ExclusiveBoundedU8::<0, 10>::try_from(ExclusiveBoundedI64::<-10, 300>::from(-1)).is_err());
+        assert!(ExclusiveBoundedU8::<0, 10>::try_from(ExclusiveBoundedI64::<-10, 300>::from(5)).unwrap() == 5);
+
+        // the float and environment helpers name the range of the type
+        assert!(HalfOpenBoundedI32::<0, 10>::try_from_f64(9.5, Rounding::Ceil).is_err());
+        assert!(HalfOpenBoundedI32::<0, 10>::from_f64_clamped(f64::INFINITY, Rounding::Floor).unwrap() == 9);
+        assert!(ExclusiveBoundedI32::<0, 10>::from_f64_clamped(-3.0, Rounding::Floor).unwrap() == 1);
+        assert!(ExclusiveBoundedI32::<0, 1>::from_f64_clamped(0.0, Rounding::Floor).unwrap().is_err());
+        std::env::set_var("BOUNDED_TYPES_TEST_EXCLUSIVE", "10");
+        let env_err: HalfOpenBoundedU16FromEnvError<0, 10> = HalfOpenBoundedU16::<0, 10>::from_env("BOUNDED_TYPES_TEST_EXCLUSIVE").unwrap_err();
+        assert!(env_err.out_of_bounds().unwrap().value() == 10);
+        assert!(env_err.to_string().ends_with("allowed range is 0..10"));
+        assert!(ExclusiveBoundedU16::<0, 11>::from_env("BOUNDED_TYPES_TEST_EXCLUSIVE").unwrap() == 10);
+    }
+
+    #[test]
+    fn one_sided_bounds() {
+        use super::*;
+
+        let non_negative: AtLeastI64<0> = 5.into();
+        assert!(non_negative == 5);
+        assert!(non_negative > -1);
+        assert!(AtLeastI64::<0>::from(i64::MAX) == i64::MAX);
+        assert!(AtLeastI64::<0>::from(u128::MAX).is_err());
+        assert!(AtMostU32::<1000>::from(1000) == 1000);
+        assert!(AtMostU32::<1000>::from(0) < 1);
+        assert!(1001 > AtMostU32::<1000>::from(1000));
+        assert!("-3".parse::<AtMostI8<0>>().unwrap() == -3);
+
+        let err = AtLeastI64::<0>::from(-1);
+        assert_eq!(err == -1, false);
+        assert!(err.unchecked() == -1);
+        assert!(err.unwrap_err().min_allowed() == 0);
+        assert!(err.unwrap_err().to_string() == "value -1 is out of bounds, it must be at least 0");
+        assert!(format!("{err}") == "AtLeastI64OutOfBoundsError { value: -1, min_allowed: 0 }");
+        let err: AtMostU32OutOfBoundsError<1000> = AtMostU32::<1000>::from(1001).unwrap_err();
+        assert!(err.max_allowed() == 1000);
+        assert!(err.to_string() == "value 1001 is out of bounds, it must be at most 1000");
+
+        let two_sided: BoundedI64<0, { i64::MAX }> = non_negative.two_sided();
+        assert!(two_sided == 5);
+        let two_sided: BoundedI64<0, 4> = non_negative.into();
+        assert!(two_sided.is_err());
+        let two_sided: BoundedU32<0, 1000> = AtMostU32::<1000>::from(7).into();
+        assert!(two_sided == 7);
+
+        // comparisons across bounds, with each other and with the two-sided type
+        assert!(non_negative == AtLeastI64::<5>::from(5));
+        assert!(non_negative < AtLeastI64::<-10>::from(6));
+        assert!(non_negative == AtMostI64::<10>::from(5));
+        assert!(AtMostI64::<10>::from(4) < non_negative);
+        assert!(non_negative == BoundedI64::<0, 10>::from(5));
+        assert!(BoundedI64::<0, 10>::from(6) > non_negative);
+        let err = AtLeastI64::<0>::from(-1);
+        assert_eq!(err == AtLeastI64::<-5>::from(-1), false);
+        assert_eq!(BoundedI64::<-5, 5>::from(-1) == err, false);
+        assert_eq!(AtMostI64::<0>::from(-1).partial_cmp(&err), None);
+    }
+
+    #[test]
+    fn union_domains() {
+        use super::*;
+        use std::ops::RangeInclusive;
+
+        struct NonZero;
+        impl Domain<i32> for NonZero {
+            const RANGES: &'static [RangeInclusive<i32>] = &[i32::MIN..=i32::MAX];
+            const EXCLUDED: &'static [i32] = &[0];
+        }
+
+        struct TwoRanges;
+        impl Domain<u8> for TwoRanges {
+            const RANGES: &'static [RangeInclusive<u8>] = &[1..=10, 20..=30];
+        }
+
+        struct Ports;
+        impl Domain<u16> for Ports {
+            const RANGES: &'static [RangeInclusive<u16>] = &[1..=5999, 6064..=u16::MAX];
+            const EXCLUDED: &'static [u16] = &[1, 2, 3];
+        }
+
+        struct Empty;
+        impl Domain<i8> for Empty {
+            const RANGES: &'static [RangeInclusive<i8>] = &[];
+        }
+
+        assert!(UnionBoundedI32::<NonZero>::from(-1) == -1);
+        assert!(UnionBoundedI32::<NonZero>::from(i64::MAX).is_err());
+        let zero = UnionBoundedI32::<NonZero>::from(0);
+        assert_eq!(zero == 0, false);
+        assert!(zero.unchecked() == 0);
+        assert!(zero.unwrap_err().is_excluded());
+        assert!(zero.unwrap_err().nearest_range() == Some(i32::MIN..=-1));
+        assert!(zero.unwrap_err().to_string() == "value 0 is excluded, nearest allowed range is -2147483648..=-1");
+
+        assert!(UnionBoundedU8::<TwoRanges>::from(10) == 10);
+        assert!(UnionBoundedU8::<TwoRanges>::from(20) > 10);
+        assert!(5 < UnionBoundedU8::<TwoRanges>::from(20));
+        assert!("25".parse::<UnionBoundedU8<TwoRanges>>().unwrap() == 25);
+        let err = UnionBoundedU8::<TwoRanges>::from(14);
+        let union_err: UnionBoundedU8OutOfBoundsError<TwoRanges> = err.unwrap_err();
+        assert!(!union_err.is_excluded());
+        assert!(union_err.nearest_range() == Some(1..=10));
+        assert!(UnionBoundedU8::<TwoRanges>::from(16).unwrap_err().nearest_range() == Some(20..=30));
+        assert!(UnionBoundedU8::<TwoRanges>::from(15).unwrap_err().nearest_range() == Some(1..=10));
+        assert!(UnionBoundedU8::<TwoRanges>::from(-3).unwrap_err().nearest_range() == Some(1..=10));
+        assert!(err.unwrap_err().to_string() == "value 14 is out of bounds, nearest allowed range is 1..=10");
+
+        assert!(UnionBoundedU16::<Ports>::from(8080) == 8080);
+        assert!(UnionBoundedU16::<Ports>::from(6050).unwrap_err().nearest_range() == Some(6064..=u16::MAX));
+        assert!(UnionBoundedU16::<Ports>::from(2).unwrap_err().nearest_range() == Some(4..=5999));
+        assert!(UnionBoundedU16::<Ports>::from(0).unwrap_err().nearest_range() == Some(4..=5999));
+
+        let empty = UnionBoundedI8::<Empty>::default();
+        assert!(empty.unwrap_err().nearest_range().is_none());
+        assert!(empty.unwrap_err().to_string() == "value 0 is out of bounds, the domain is empty");
+        assert!(format!("{empty}") == "UnionBoundedI8OutOfBoundsError { value: 0, nearest_range: empty domain }");
+
+        // comparisons between domains and with the bounded type of the same backing integer
+        assert!(UnionBoundedU8::<TwoRanges>::from(10) == UnionBoundedU8::<TwoRanges>::from(10));
+        assert!(UnionBoundedU16::<Ports>::from(8080) > UnionBoundedU16::<Ports>::from(4000));
+        assert!(UnionBoundedI32::<NonZero>::from(5) == BoundedI32::<0, 10>::from(5));
+        assert!(BoundedI32::<0, 10>::from(6) > UnionBoundedI32::<NonZero>::from(5));
+        assert_eq!(zero == UnionBoundedI32::<NonZero>::from(0), false);
+        assert_eq!(zero.partial_cmp(&BoundedI32::<0, 10>::from(0)), None);
+    }
+
+    #[test]
+    fn stepped_bounds() {
+        use super::*;
+
+        type BufferSize = SteppedBoundedU32<64, 4096, 64>;
+        assert!(BufferSize::from(128) == 128);
+        assert!(BufferSize::from(128) > 64);
+        assert!(4096 >= BufferSize::from(4096));
+        assert!(BufferSize::from(0).is_err());
+        assert!(BufferSize::from(4160).is_err());
+        assert!(BufferSize::from(u64::MAX).is_err());
+        assert!("256".parse::<BufferSize>().unwrap() == 256);
+
+        let misaligned = BufferSize::from(100);
+        assert_eq!(misaligned == 100, false);
+        assert!(misaligned.unchecked() == 100);
+        let err: SteppedBoundedU32OutOfBoundsError<64, 4096, 64> = misaligned.unwrap_err();
+        assert!(err.is_misaligned());
+        assert!(format!("{err:?}") == "SteppedBoundedU32OutOfBoundsError { value: 100, min_allowed: 64, max_allowed: 4096, step: 64 }");
+        assert!(misaligned.unwrap_err().step() == 64);
+        assert!(misaligned.unwrap_err().to_string() == "value 100 is misaligned, it must be 64 plus a multiple of 64");
+        let out_of_bounds = BufferSize::from(8192);
+        assert!(!out_of_bounds.unwrap_err().is_misaligned());
+        assert!(out_of_bounds.unwrap_err().to_string() == "value 8192 is out of bounds, allowed range is 64..=4096");
+
+        assert!(BufferSize::round_to_step(100) == 128);
+        assert!(BufferSize::round_to_step(95) == 64);
+        assert!(BufferSize::round_to_step(96) == 128);
+        assert!(BufferSize::round_to_step(0) == 64);
+        assert!(BufferSize::round_to_step(u32::MAX) == 4096);
+        // the last value is the last step, not MAX
+        assert!(SteppedBoundedI8::<-10, 10, 3>::round_to_step(10) == 8);
+        assert!(SteppedBoundedI8::<-10, 10, 3>::round_to_step(-9) == -10);
+        assert!(SteppedBoundedI8::<-128, 127, 100>::round_to_step(127) == 72);
+        assert!(SteppedBoundedI8::<10, 0, 1>::round_to_step(5).is_err());
+
+        assert!(BufferSize::values().count() == 64);
+        assert!(BufferSize::values().next_back().unwrap() == 4096);
+        let slider: Vec<i8> = SteppedBoundedI8::<-10, 10, 5>::values().map(|val| val.unchecked()).collect();
+        assert!(slider == vec![-10, -5, 0, 5, 10]);
+        assert!(SteppedBoundedI8::<10, 0, 1>::values().count() == 0);
+        assert!(SteppedBoundedU128::<0, { u128::MAX }, { u128::MAX }>::values().count() == 2);
+
+        let bounded: BoundedU32<0, 4096> = BufferSize::from(128).into();
+        assert!(bounded == 128);
+
+        // comparisons across bounds and steps, and with the bounded type of the same backing integer
+        assert!(BufferSize::from(128) == SteppedBoundedU32::<0, 1024, 32>::from(128));
+        assert!(BufferSize::from(128) < SteppedBoundedU32::<0, 1024, 32>::from(160));
+        assert!(BufferSize::from(128) == bounded);
+        assert!(BoundedU32::<0, 100>::from(100) < BufferSize::from(128));
+        assert_eq!(misaligned == SteppedBoundedU32::<0, 1024, 4>::from(100), false);
+        assert_eq!(SteppedBoundedU32::<0, 1024, 4>::from(100) == misaligned, false);
+        assert_eq!(misaligned.partial_cmp(&bounded), None);
+    }
+
+    #[test]
+    fn dyn_bounded() {
+        use super::*;
+        use serde::de::value::{Error, I64Deserializer};
+        use serde::de::{DeserializeSeed, IntoDeserializer};
+
+        let bounds = DynBounds::new(2_i64, 10);
+        let ok = bounds.bound(5);
+        assert!(ok == 5);
+        assert!(ok > 4);
+        assert!(6 > ok);
+        assert!(ok.bounds().max() == 10);
+        assert!(ok.with_value(11).is_err());
+        assert!(DynBounded::new(0.5, 0.0, 1.0) < 0.75);
+
+        let err = DynBounded::new(11_i64, 2, 10);
+        assert_eq!(err == 11, false);
+        assert_eq!(err > 5, false);
+        assert!(err.unchecked() == 11);
+        assert!(err.to_string() == "value 11 is out of bounds, allowed range is 2..=10");
+        assert!(err.as_ref().unwrap_err().max_allowed() == 10);
+
+        // conversions only succeed if the bounds match
+        let bounded: BoundedI64<2, 10> = BoundedI64::try_from(ok).unwrap();
+        assert!(bounded == 5);
+        assert!(BoundedI64::<0, 10>::try_from(ok).is_err());
+        assert!(BoundedI64::<2, 10>::try_from(err).unwrap().is_err());
+        let dynamic: DynBounded<i64> = bounded.into();
+        assert!(dynamic == 5);
+        assert!(dynamic.bounds() == bounds);
+
+        let deserializer: I64Deserializer<Error> = 7_i64.into_deserializer();
+        assert!(bounds.deserialize(deserializer).unwrap() == 7);
+        let deserializer: I64Deserializer<Error> = 11_i64.into_deserializer();
+        assert!(bounds.deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    fn any_bounded() {
+        use super::*;
+
+        struct NonZero;
+        impl Domain<i32> for NonZero {
+            const RANGES: &'static [std::ops::RangeInclusive<i32>] = &[i32::MIN..=i32::MAX];
+            const EXCLUDED: &'static [i32] = &[0];
+        }
+
+        let mut values: Vec<Box<dyn AnyBounded>> = vec![
+            Box::new(BoundedI8::<-5, 5>::from(7)),
+            Box::new(StrictBoundedU8::<1, 5>::try_from(3).unwrap()),
+            Box::new(HalfOpenBoundedI16::<0, 10>::from(4)),
+            Box::new(ExclusiveBoundedI16::<0, 10>::from(4)),
+            Box::new(SteppedBoundedU32::<0, 100, 30>::from(60)),
+            Box::new(UnionBoundedI32::<NonZero>::from(-1)),
+            Box::new(AtLeastI64::<10>::from(10)),
+            Box::new(AtMostU128::<1000>::from(1)),
+        ];
+        let bounds: Vec<_> = values.iter().map(|value| (value.min_i128(), value.max_i128())).collect();
+        assert!(
+            bounds
+                == [
+                    (Some(-5), Some(5)),
+                    (Some(1), Some(5)),
+                    (Some(0), Some(9)),
+                    (Some(1), Some(9)),
+                    (Some(0), Some(90)),
+                    (Some(i32::MIN.into()), Some(i32::MAX.into())),
+                    (Some(10), Some(i64::MAX.into())),
+                    (Some(0), Some(1000)),
+                ]
+        );
+        assert!(values.iter().map(|value| value.is_valid()).collect::<Vec<_>>() == [false, true, true, true, true, true, true, true]);
+        assert!(values[0].value_i128() == Some(7));
+        assert!(values[0].type_name().ends_with("BoundedI8<-5, 5>"));
+
+        values[0].try_set_i128(-5).unwrap();
+        assert!(values[0].is_valid());
+        let err = values[1].try_set_i128(6).unwrap_err();
+        assert!(err.value() == 6);
+        assert!(err.to_string().starts_with("value 6 is not allowed for "));
+        assert!(values[1].value_i128() == Some(3));
+        assert!(values[2].try_set_i128(10).is_err());
+        assert!(values[4].try_set_i128(45).is_err());
+        assert!(values[5].try_set_i128(0).is_err());
+        assert!(values[5].try_set_i128(i128::MAX).is_err());
+        assert!(values[7].try_set_i128(-1).is_err());
+        assert!(values[7].value_i128() == Some(1));
+
+        // values that don't fit into i128
+        let large: &dyn AnyBounded = &BoundedU128::<0, { u128::MAX }>::from(u128::MAX);
+        assert!(large.max_i128() == None);
+        assert!(large.value_i128() == None);
+    }
+
+    #[test]
+    fn refined() {
+        use super::predicates::{And, Even, InRange, MultipleOf, NonZero, Not, Or};
+        use super::*;
+
+        type Percentage = Refined<u8, InRange<0, 100>>;
+        type OddMultipleOfThree = Refined<i64, And<MultipleOf<3>, Not<Even>>>;
+        type Divisor = Refined<i32, Or<InRange<1, 10>, InRange<-10, -1>>>;
+        type Port = Refined<u16, BoundedU16<1, 1023>>;
+
+        assert!(Percentage::from(100) == 100);
+        assert!(Percentage::from(101).is_err());
+        assert!(Percentage::from(5) < Percentage::from(6));
+        assert!(5 == Percentage::from(5));
+
+        assert!(OddMultipleOfThree::from(-9).is_ok());
+        assert!(OddMultipleOfThree::from(6).is_err());
+        assert!(OddMultipleOfThree::from(7).is_err());
+        let err = OddMultipleOfThree::from(6).unwrap_err();
+        assert!(err.value() == 6);
+        assert!(err.to_string() == "value 6 doesn't satisfy (a multiple of 3 and not even)");
+
+        assert!(Divisor::from(-3).is_ok());
+        assert!(Divisor::from(0).is_err());
+        assert_eq!(Divisor::from(0) == 0, false);
+        assert!(Refined::<i32, NonZero>::from(0).is_err());
+        assert!(Refined::<f64, NonZero>::from(0.5).is_ok());
+        assert!(Refined::<u128, Even>::from(u128::MAX - 1).is_ok());
+        assert!(Refined::<u128, InRange<0, 10>>::from(u128::MAX).is_err());
+        assert!(Refined::<u8, MultipleOf<0>>::from(0).is_ok());
+        assert!(Refined::<u8, MultipleOf<0>>::from(3).is_err());
+
+        // bounded values are checked against the predicate
+        let refined: Refined<i16, Even> = BoundedI16::<0, 10>::from(4).into();
+        assert!(refined == 4);
+        assert!(Refined::<i16, Even>::from(BoundedI16::<0, 10>::from(5)).is_err());
+
+        assert!("42".parse::<Refined<u8, Even>>().unwrap() == 42);
+        assert!(Refined::<u8, Even>::from(3).to_string() == "RefinementError { value: 3 }");
+
+        // the bounded types are predicates, and convert into refined types with them and back
+        assert!(Port::from(80) == 80);
+        assert!(Port::from(0).unwrap_err().to_string() == "value 0 doesn't satisfy 1..=1023");
+        assert!(Refined::<u16, HalfOpenBoundedU16<1, 1024>>::from(1024).is_err());
+        assert!(Refined::<u128, BoundedU128<{ u128::MAX - 1 }, { u128::MAX }>>::from(u128::MAX).is_ok());
+        assert!(Refined::<u16, And<BoundedU16<1, 1023>, Even>>::from(80).is_ok());
+        for value in [0, 80, 1024] {
+            let bounded = BoundedU16::<1, 1023>::from(value);
+            let round_trip = BoundedU16::<1, 1023>::from(Port::from(bounded));
+            assert!(round_trip.unchecked() == value);
+            assert!(round_trip.is_ok() == bounded.is_ok());
+        }
+        let err: u16::OutOfBoundsError<1, 1023> = Port::from(0).unwrap_err().into();
+        assert!(err.value() == 0);
+        let err: RefinementError<u16, BoundedU16<1, 1023>> = err.into();
+        assert!(err.value() == 0);
+        // other predicates are checked on conversion
+        assert!(BoundedU8::<0, 10>::from(Refined::<u8, Even>::from(12)).is_err());
+        assert!(BoundedU8::<0, 10>::from(Refined::<u8, Even>::from(3)).unchecked() == 3);
+
+        // both are serialized the same way, so they can read each other
+        for value in [0, 80] {
+            let json = serde_json::to_string(&BoundedU16::<1, 1023>::from(value)).unwrap();
+            assert!(json == serde_json::to_string(&Port::from(value)).unwrap());
+            let refined: Port = serde_json::from_str(&json).unwrap();
+            assert!(refined.unchecked() == value);
+            let bounded: BoundedU16<1, 1023> = serde_json::from_str(&serde_json::to_string(&refined).unwrap()).unwrap();
+            assert!(bounded.is_ok() == refined.is_ok());
+        }
+    }
+
+    #[test]
+    fn soft_bounds() {
+        use super::*;
+        type Temperature = SoftBoundedI16<-40, 85, 0, 45>;
+
+        assert!(Temperature::from(0).classify() == Classification::Normal);
+        assert!(Temperature::from(45).is_normal());
+        assert!(Temperature::from(-1).classify() == Classification::Warning(Warning::Low));
+        assert!(Temperature::from(85).classify() == Classification::Warning(Warning::High));
+        assert!(Temperature::from(86).classify() == Classification::OutOfBounds);
+        assert!(Temperature::from(i64::MIN).classify() == Classification::OutOfBounds);
+        assert!(Temperature::classify_value(-40) == Classification::Warning(Warning::Low));
+
+        let warm = Temperature::from(60);
+        assert!(warm.is_ok());
+        assert!(warm == 60);
+        assert!(warm > 59_u8);
+        assert!(61 > warm);
+        assert!(warm.hard() == BoundedI16::<-40, 85>::from(60));
+        assert!(BoundedI16::<0, 50>::from(warm).is_err());
+        assert!(warm.to_string() == "60");
+
+        let hot = Temperature::from(90);
+        assert_eq!(hot == 90, false);
+        assert!(hot.unchecked() == 90);
+        assert!(hot.as_ref().unwrap_err().max_allowed() == 85);
+        assert!("30".parse::<Temperature>().unwrap().is_normal());
+        assert!(Temperature::is_in_bounds(&-40));
+
+        // comparisons across bounds, and with the bounded type of the same backing integer
+        assert!(warm == SoftBoundedI16::<0, 100, 20, 80>::from(60));
+        assert!(warm < SoftBoundedI16::<0, 100, 20, 80>::from(61));
+        assert!(warm == BoundedI16::<0, 60>::from(60));
+        assert!(BoundedI16::<0, 100>::from(59) < warm);
+        assert_eq!(hot == SoftBoundedI16::<0, 100, 20, 80>::from(90), false);
+        assert_eq!(BoundedI16::<0, 100>::from(90) == hot, false);
+        assert_eq!(warm.partial_cmp(&BoundedI16::<0, 50>::from(90)), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        use super::*;
+        use serde::de::value::{Error, U64Deserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let a = Mod::<7>::new(12);
+        assert!(a == 5);
+        assert!(a.modulus() == 7);
+        assert!(a + Mod::new(4) == 2);
+        assert!(Mod::<7>::new(2) - a == 4);
+        assert!(-a == 2);
+        assert!(-Mod::<7>::new(0) == 0);
+        assert!(a * a == 4);
+        assert!(a.pow(0) == 1);
+        assert!(a.pow(6) == 1);
+        assert!(a / Mod::new(3) == 4);
+        assert!(a.to_string() == "5 (mod 7)");
+
+        let mut b = Mod::<7>::new(1);
+        b += a;
+        b *= Mod::new(2);
+        b -= Mod::new(1);
+        b /= Mod::new(3);
+        assert!(b == 6);
+
+        // every value is invertible if the modulus is prime
+        for value in 1..7 {
+            let value = Mod::<7>::new(value);
+            assert!(value * value.inverse().unwrap() == 1);
+        }
+        assert!(Mod::<7>::new(0).inverse() == None);
+        assert!(Mod::<12>::new(5).inverse() == Some(Mod::new(5)));
+        assert!(Mod::<12>::new(6).inverse() == None);
+        assert!(Mod::<12>::new(6).checked_div(Mod::new(4)) == None);
+        assert!(Mod::<1>::new(9) == 0);
+
+        // large moduli don't overflow
+        let large = Mod::<{ u64::MAX }>::new(u64::MAX - 1);
+        assert!(large + large == u64::MAX - 2);
+        assert!(large * large == 1);
+        assert!(large.inverse() == Some(large));
+
+        let bounded: BoundedU64<0, 6> = a.into();
+        assert!(bounded == 5);
+        assert!(BoundedU64::<0, 4>::from(a).is_err());
+        assert!(Mod::<7>::try_from(BoundedU64::<0, 100>::from(50)).unwrap() == 1);
+        assert!(Mod::<7>::try_from(BoundedU64::<0, 100>::from(150)).is_err());
+
+        let deserializer: U64Deserializer<Error> = 6_u64.into_deserializer();
+        assert!(Mod::<7>::deserialize(deserializer).unwrap() == 6);
+        let deserializer: U64Deserializer<Error> = 7_u64.into_deserializer();
+        assert!(Mod::<7>::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "coprime")]
+    fn modular_division_by_zero() {
+        let _ = super::Mod::<7>::new(1) / super::Mod::new(0);
+    }
+
+    #[test]
+    fn defaults() {
+        use super::*;
+        struct Gap;
+        impl Domain<i8> for Gap {
+            const RANGES: &'static [std::ops::RangeInclusive<i8>] = &[-20..=-3, 3..=20];
+        }
+        struct Empty;
+        impl Domain<i8> for Empty {
+            const RANGES: &'static [std::ops::RangeInclusive<i8>] = &[];
+        }
+
+        assert!(BoundedU8::<3, 10>::default() == 3);
+        assert!(BoundedI64::<-10, 10>::default() == 0);
+        assert!(BoundedI64::<-10, -2>::default() == -2);
+        assert!(BoundedI64::<10, 2>::default().is_err());
+        assert!(StrictBoundedI16::<-7, -3>::default().get() == -3);
+        assert!(HalfOpenBoundedI32::<-10, 0>::default() == -1);
+        assert!(ExclusiveBoundedU32::<0, 10>::default() == 1);
+        assert!(StrictHalfOpenBoundedI32::<-10, 0>::default() == -1);
+        assert!(StrictExclusiveBoundedU32::<0, 10>::default() == 1);
+        assert!(SteppedBoundedI32::<-10, 10, 3>::default() == -1);
+        assert!(SoftBoundedU16::<5, 100, 20, 80>::default() == 5);
+        assert!(UnionBoundedI8::<Gap>::default() == -3);
+        assert!(UnionBoundedI8::<Empty>::default().is_err());
+        assert!(AtLeastU64::<100>::default() == 100);
+        assert!(AtMostI8::<-100>::default() == -100);
+        assert!(AtMostI8::<100>::default() == 0);
+
+        let port = DefaultedBoundedU16::<1024, 65535, 8080>::default();
+        assert!(port == 8080);
+        assert!(port > 8000_u32);
+        assert!(9000 > port);
+        assert!(port.into_inner() == BoundedU16::<1024, 65535>::from(8080));
+        assert!(port.to_string() == "8080");
+        assert!(DefaultedBoundedU16::<1024, 65535, 8080>::from(80).is_err());
+        assert!("2000".parse::<DefaultedBoundedU16<1024, 65535, 8080>>().unwrap() == 2000);
+        let mut port = port;
+        *port = BoundedU16::from(443);
+        assert!(port.is_err());
+    }
+
     #[test]
     #[allow(clippy::useless_conversion)]
     fn illegal_operations() {
@@ -0,0 +1,204 @@
+//! Integers modulo `N`, with the arithmetic of the ring ℤ/Nℤ.
+
+use crate::u64::{BoundedU64, OutOfBoundsError};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An integer modulo `N`. The value is always reduced to `0..N`, so unlike the bounded types it can't be out of bounds.
+///
+/// ```
+/// use bounded_types::{BoundedU64, Mod};
+///
+/// let weekday = Mod::<7>::new(5); // saturday, if monday is 0
+/// assert_eq!(weekday + Mod::new(3), Mod::new(1));
+/// assert_eq!(Mod::<7>::new(3).inverse(), Some(Mod::new(5)));
+/// let bounded: BoundedU64<0, 6> = weekday.into();
+/// assert!(bounded == 5);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mod<const N: u64>(u64);
+
+impl<const N: u64> Mod<N> {
+    /// Reduces a value modulo `N`.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        const { assert!(N > 0, "the modulus has to be positive") };
+        Self(value % N)
+    }
+
+    /// Returns the value, which is within `0..N`.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the modulus `N`.
+    #[must_use]
+    pub const fn modulus(self) -> u64 {
+        N
+    }
+
+    /// Raises the value to the power of `exp`, by repeated squaring.
+    #[must_use]
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::new(1);
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp /= 2;
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse, which exists if the value is coprime to `N`, so always unless it is 0 if `N` is prime.
+    #[must_use]
+    pub fn inverse(self) -> Option<Self> {
+        // extended euclidean algorithm, with the coefficients of the value
+        let (mut remainder, mut next_remainder) = (i128::from(N), i128::from(self.0));
+        let (mut coefficient, mut next_coefficient) = (0_i128, 1_i128);
+        while next_remainder != 0 {
+            let quotient = remainder / next_remainder;
+            (remainder, next_remainder) = (next_remainder, remainder - quotient * next_remainder);
+            (coefficient, next_coefficient) = (next_coefficient, coefficient - quotient * next_coefficient);
+        }
+        if remainder != 1 {
+            return None;
+        }
+        // the coefficient is within -N..N, so it fits into u64 after adding N
+        u64::try_from(coefficient.rem_euclid(i128::from(N))).ok().map(Self::new)
+    }
+
+    /// Divides by multiplying with the inverse of `other`. Returns `None` if `other` isn't invertible.
+    #[must_use]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        other.inverse().map(|inverse| self * inverse)
+    }
+}
+
+impl<const N: u64> std::fmt::Display for Mod<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.0, N)
+    }
+}
+
+impl<const N: u64> From<u64> for Mod<N> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const N: u64> Add for Mod<N> {
+    type Output = Self;
+    #[allow(clippy::cast_possible_truncation)]
+    fn add(self, other: Self) -> Self {
+        // the sum is smaller than N, so the cast is lossless
+        Self(((u128::from(self.0) + u128::from(other.0)) % u128::from(N)) as u64)
+    }
+}
+
+impl<const N: u64> Sub for Mod<N> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const N: u64> Mul for Mod<N> {
+    type Output = Self;
+    #[allow(clippy::cast_possible_truncation)]
+    fn mul(self, other: Self) -> Self {
+        // the product is smaller than N, so the cast is lossless
+        Self((u128::from(self.0) * u128::from(other.0) % u128::from(N)) as u64)
+    }
+}
+
+impl<const N: u64> Div for Mod<N> {
+    type Output = Self;
+    /// # Panics
+    /// Panics if `other` isn't invertible, like an integer division by zero. Use `checked_div` to handle this case.
+    fn div(self, other: Self) -> Self {
+        self.checked_div(other).expect("attempt to divide by a value that isn't coprime to the modulus")
+    }
+}
+
+impl<const N: u64> Neg for Mod<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(N - self.0)
+    }
+}
+
+impl<const N: u64> AddAssign for Mod<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: u64> SubAssign for Mod<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: u64> MulAssign for Mod<N> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const N: u64> DivAssign for Mod<N> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const N: u64> PartialEq<u64> for Mod<N> {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Inferred through symmetry.
+impl<const N: u64> PartialEq<Mod<N>> for u64 {
+    fn eq(&self, other: &Mod<N>) -> bool {
+        other == self
+    }
+}
+
+/// Converts into a bounded type, usually `BoundedU64<0, {N - 1}>`. The value is checked against the bounds.
+impl<const N: u64, const MIN: u64, const MAX: u64> From<Mod<N>> for BoundedU64<MIN, MAX> {
+    fn from(other: Mod<N>) -> Self {
+        Self::from(other.get())
+    }
+}
+
+/// Reduces an in-bounds value modulo `N`. Out-of-bounds values are rejected.
+impl<const N: u64, const MIN: u64, const MAX: u64> TryFrom<BoundedU64<MIN, MAX>> for Mod<N> {
+    type Error = OutOfBoundsError<MIN, MAX>;
+    fn try_from(other: BoundedU64<MIN, MAX>) -> Result<Self, Self::Error> {
+        (*other).map(Self::new)
+    }
+}
+
+impl<const N: u64> Serialize for Mod<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Values that aren't reduced are rejected, so that they aren't silently changed.
+impl<'de, const N: u64> Deserialize<'de> for Mod<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        if value < N {
+            Ok(Self(value))
+        } else {
+            Err(D::Error::custom(format_args!("value {value} is out of bounds, allowed range is 0..{N}")))
+        }
+    }
+}
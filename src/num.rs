@@ -0,0 +1,111 @@
+//! Support for generic numeric code through the traits of `num-traits`.
+//!
+//! The checked and saturating traits of `num-traits` require the matching operators, so `Add`, `Sub` and `Mul` are implemented as well when this feature is enabled.
+//! Their result has to be a bounded value of the same type, so an out-of-bounds result (or an out-of-bounds operand) produces an out-of-bounds value that holds the exact result, just like `From` does.
+//! If the result doesn't even fit into the backing integer, the saturated result is stored as out-of-bounds value, like `From` does for numbers that don't fit, so the operators never panic.
+
+/// Implements an arithmetic operator and its `num-traits` checked and saturating counterparts.
+macro_rules! derive_num_operator {
+    ( $type: ident, $bound:ty, $int:ty; $op_trait:ident, $op:ident, $checked_trait:ident, $checked:ident, $saturating_trait:ident, $saturating:ident ) => {
+        #[cfg(feature = "num-traits")]
+        impl<const MIN: $bound, const MAX: $bound> std::ops::$op_trait for $type<MIN, MAX> {
+            type Output = Self;
+            /// The result is out of bounds if an operand is. If the result overflows the backing integer, the saturated result is stored as out-of-bounds value, even if it is within the bounds.
+            fn $op(self, other: Self) -> Self {
+                match (self.0, other.0, self.unchecked().$checked(other.unchecked())) {
+                    (Ok(_), Ok(_), Some(val)) => Self::from(val),
+                    (_, _, Some(val)) => Self::out_of_bounds(val),
+                    (_, _, None) => Self::out_of_bounds(self.unchecked().$saturating(other.unchecked())),
+                }
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl<const MIN: $bound, const MAX: $bound> ::num_traits::$checked_trait for $type<MIN, MAX> {
+            /// Returns `None` if an operand or the result is out of bounds.
+            fn $checked(&self, other: &Self) -> Option<Self> {
+                match (self.0, other.0) {
+                    (Ok(self_val), Ok(other_val)) => self_val
+                        .$checked(other_val)
+                        .filter(Self::is_in_bounds)
+                        .map(|val| Self(Ok(val))),
+                    _ => None,
+                }
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl<const MIN: $bound, const MAX: $bound> ::num_traits::$saturating_trait for $type<MIN, MAX> {
+            /// Saturates at the smallest and largest allowed value. If an operand is out of bounds, the result is out of bounds as well.
+            fn $saturating(&self, other: &Self) -> Self {
+                match (self.0, other.0) {
+                    (Ok(self_val), Ok(other_val)) => Self::clamp_to_bounds(self_val.$saturating(other_val)),
+                    _ => Self::out_of_bounds(self.unchecked().$saturating(other.unchecked())),
+                }
+            }
+        }
+    };
+}
+
+/// Implements the `num-traits` conversion and bound traits for a bounded type.
+macro_rules! derive_num_traits {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        derive_num_operator!($type, $bound, $int; Add, add, CheckedAdd, checked_add, SaturatingAdd, saturating_add);
+        derive_num_operator!($type, $bound, $int; Sub, sub, CheckedSub, checked_sub, SaturatingSub, saturating_sub);
+        derive_num_operator!($type, $bound, $int; Mul, mul, CheckedMul, checked_mul, SaturatingMul, saturating_mul);
+
+        /// If no value is allowed, `MIN` and `MAX` are returned as out-of-bounds values.
+        #[cfg(feature = "num-traits")]
+        impl<const MIN: $bound, const MAX: $bound> ::num_traits::Bounded for $type<MIN, MAX> {
+            fn min_value() -> Self {
+                Self::from(Self::allowed_range().map_or(MIN, |(first, _)| first))
+            }
+
+            fn max_value() -> Self {
+                Self::from(Self::allowed_range().map_or(MAX, |(_, last)| last))
+            }
+        }
+
+        /// Out-of-bounds values can't be converted and return `None`.
+        #[cfg(feature = "num-traits")]
+        impl<const MIN: $bound, const MAX: $bound> ::num_traits::ToPrimitive for $type<MIN, MAX> {
+            derive_num_traits!(@to $int; to_isize isize, to_i8 i8, to_i16 i16, to_i32 i32, to_i64 i64, to_i128 i128);
+            derive_num_traits!(@to $int; to_usize usize, to_u8 u8, to_u16 u16, to_u32 u32, to_u64 u64, to_u128 u128);
+            derive_num_traits!(@to $int; to_f32 f32, to_f64 f64);
+        }
+
+        /// Returns `None` for values that are out of bounds.
+        #[cfg(feature = "num-traits")]
+        impl<const MIN: $bound, const MAX: $bound> ::num_traits::FromPrimitive for $type<MIN, MAX> {
+            derive_num_traits!(@from $int; from_isize isize, from_i8 i8, from_i16 i16, from_i32 i32, from_i64 i64, from_i128 i128);
+            derive_num_traits!(@from $int; from_usize usize, from_u8 u8, from_u16 u16, from_u32 u32, from_u64 u64, from_u128 u128);
+            derive_num_traits!(@from $int; from_f32 f32, from_f64 f64);
+        }
+
+        /// Returns `None` for values that are out of bounds.
+        #[cfg(feature = "num-traits")]
+        impl<const MIN: $bound, const MAX: $bound> ::num_traits::NumCast for $type<MIN, MAX> {
+            fn from<T: ::num_traits::ToPrimitive>(n: T) -> Option<Self> {
+                <$int as ::num_traits::NumCast>::from(n)
+                    .filter(Self::is_in_bounds)
+                    .map(|val| Self(Ok(val)))
+            }
+        }
+    };
+    (@to $int:ty; $( $method:ident $prim:ty ),* ) => {
+        $(
+        fn $method(&self) -> Option<$prim> {
+            self.0.ok().and_then(|val| ::num_traits::ToPrimitive::$method(&val))
+        }
+        )*
+    };
+    (@from $int:ty; $( $method:ident $prim:ty ),* ) => {
+        $(
+        fn $method(n: $prim) -> Option<Self> {
+            <$int as ::num_traits::FromPrimitive>::$method(n)
+                .filter(Self::is_in_bounds)
+                .map(|val| Self(Ok(val)))
+        }
+        )*
+    };
+}
@@ -0,0 +1,182 @@
+//! Bounded types that only have a lower or an upper bound, like `AtLeastI64<0>` or `AtMostU32<1000>`.
+//!
+//! They behave like the two-sided type with the other bound at the limit of the backing integer, but their errors only mention the relevant bound.
+//! They compare with each other and with the two-sided type of the same backing integer.
+
+/// Derives the traits that define the relation of a one-sided type to other numeric types. Conversions and comparisons are deduced from the two-sided type.
+macro_rules! derive_one_sided_numeric_traits {
+    ( $name: ident, $bound:ty, $param: ident; $( $numeric:ty ),* ) => {
+        $(
+        impl<const $param: $bound> From<$numeric> for $name<$param> {
+            fn from(other: $numeric) -> Self {
+                Self::from_two_sided(other.into())
+            }
+        }
+
+        impl<const $param: $bound> PartialEq<$numeric> for $name<$param> {
+            fn eq(&self, other: &$numeric) -> bool {
+                self.two_sided() == *other
+            }
+        }
+
+        impl<const $param: $bound> PartialOrd<$numeric> for $name<$param> {
+            fn partial_cmp(&self, other: &$numeric) -> Option<Ordering> {
+                self.two_sided().partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const $param: $bound> PartialEq<$name<$param>> for $numeric {
+            fn eq(&self, other: &$name<$param>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const $param: $bound> PartialOrd<$name<$param>> for $numeric {
+            fn partial_cmp(&self, other: &$name<$param>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )*
+    };
+}
+
+/// Derives the comparisons of `$name` with `$other`, which are generic over `$generics`. An out-of-bounds value on either side compares false.
+macro_rules! derive_one_sided_comparisons {
+    ( [ $( $generics: tt )* ] $name: ident < $( $args: tt ),* >, $other: ident < $( $other_args: tt ),* > ) => {
+        impl< $( $generics )* > PartialEq<$other< $( $other_args ),* >> for $name< $( $args ),* > {
+            fn eq(&self, other: &$other< $( $other_args ),* >) -> bool {
+                match **other {
+                    Ok(other_val) => *self == other_val,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl< $( $generics )* > PartialOrd<$other< $( $other_args ),* >> for $name< $( $args ),* > {
+            fn partial_cmp(&self, other: &$other< $( $other_args ),* >) -> Option<Ordering> {
+                match **other {
+                    Ok(other_val) => self.partial_cmp(&other_val),
+                    Err(_) => None,
+                }
+            }
+        }
+    };
+}
+
+/// Generates a one-sided bounded type along with its error type. `$param` is the name of the bound, and `$lower`/`$upper` are the bounds of the equivalent two-sided type.
+macro_rules! generate_one_sided_type {
+    ( $type: ident, $bound:ty, $int:ty; $name: ident, $error: ident, $param: ident, $allowed: ident, $lower: tt, $upper: tt, $relation: literal ) => {
+        #[derive(Shrinkwrap, Copy, Clone)]
+        #[derive(Serialize, Deserialize)]
+        #[doc = concat!("The error that is returned when you attempt to assign an out-of-bounds value to an `", stringify!($name), "`.")]
+        pub struct $error<const $param: $bound>($int);
+
+        impl<const $param: $bound> $error<$param> {
+            /// Returns the value that was attempted to be passed.
+            #[must_use]
+            pub fn value(&self) -> $int {
+                self.0
+            }
+
+            /// Returns the bound.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn $allowed(&self) -> $bound {
+                $param
+            }
+        }
+
+        impl<const $param: $bound> Debug for $error<$param> {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt.debug_struct(stringify!($error))
+                    .field("value", &self.value())
+                    .field(stringify!($allowed), &self.$allowed())
+                    .finish()
+            }
+        }
+
+        impl<const $param: $bound> std::fmt::Display for $error<$param> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!("value {} is out of bounds, it must be ", $relation, " {}"), self.value(), $param)
+            }
+        }
+
+        impl<const $param: $bound> std::error::Error for $error<$param> {}
+
+        #[derive(Shrinkwrap, Debug, Copy, Clone)]
+        #[derive(Serialize, Deserialize)]
+        #[doc = concat!("An `", stringify!($int), "` element that is forced to be ", $relation, " `", stringify!($param), "`.")]
+        pub struct $name<const $param: $bound>(Result<$int, $error<$param>>);
+
+        impl<const $param: $bound> $name<$param> {
+            /// Returns the numeric value stored in the struct, but overrides the bounds check.
+            #[must_use]
+            pub fn unchecked(&self) -> $int {
+                match &self.0 {
+                    Ok(val) => *val,
+                    Err(err) => err.value(),
+                }
+            }
+
+            /// Function that returns whether a value is within the bounds.
+            pub fn is_in_bounds(val: &impl PartialOrd<$int>) -> bool {
+                $type::<$lower, $upper>::is_in_bounds(val)
+            }
+
+            /// Converts the value into the equivalent two-sided type.
+            #[must_use]
+            pub fn two_sided(self) -> $type<$lower, $upper> {
+                $type::from(self.unchecked())
+            }
+
+            /// Converts from the equivalent two-sided type. The error value is kept.
+            fn from_two_sided(other: $type<$lower, $upper>) -> Self {
+                Self(other.0.map_err(|err| $error(err.value())))
+            }
+        }
+
+        impl<const $param: $bound> std::fmt::Display for $name<$param> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match &self.0 {
+                    Ok(val) => write!(f, "{}", val),
+                    Err(err) => write!(f, "{:?}", err),
+                }
+            }
+        }
+
+        impl<const $param: $bound> FromStr for $name<$param> {
+            type Err = <$bound as FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <$bound>::from_str(s).map($name::from)
+            }
+        }
+
+        /// Converts into a two-sided type. The value is checked against the new bounds.
+        impl<const $param: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound> From<$name<$param>> for $type<OTHER_MIN, OTHER_MAX> {
+            fn from(other: $name<$param>) -> Self {
+                Self::from(other.unchecked())
+            }
+        }
+
+        derive_one_sided_comparisons!([const $param: $bound, const OTHER: $bound] $name<$param>, $name<OTHER>);
+        derive_one_sided_comparisons!([const $param: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound] $name<$param>, $type<OTHER_MIN, OTHER_MAX>);
+        derive_one_sided_comparisons!([const $param: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound] $type<OTHER_MIN, OTHER_MAX>, $name<$param>);
+        derive_one_sided_numeric_traits!($name, $bound, $param; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    };
+}
+
+/// Generates the `AtLeast` and `AtMost` types of a backing integer. Their error types are named like them with an `OutOfBoundsError` suffix.
+macro_rules! generate_one_sided {
+    ( $type: ident, $bound:ty, $int:ty; $at_least: ident, $at_most: ident ) => {
+        paste::paste! {
+        generate_one_sided_type!($type, $bound, $int; $at_least, [<$at_least OutOfBoundsError>], MIN, min_allowed, MIN, { <$int>::MAX }, "at least");
+        generate_one_sided_type!($type, $bound, $int; $at_most, [<$at_most OutOfBoundsError>], MAX, max_allowed, { <$int>::MIN }, MAX, "at most");
+        }
+        derive_one_sided_comparisons!([const MIN: $bound, const MAX: $bound] $at_least<MIN>, $at_most<MAX>);
+        derive_one_sided_comparisons!([const MIN: $bound, const MAX: $bound] $at_most<MAX>, $at_least<MIN>);
+        derive_any_bounded!($type, $bound, $int; $at_least, $at_most);
+        derive_default!($type, $bound, $int; $at_least, $at_most);
+    };
+}
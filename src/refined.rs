@@ -0,0 +1,253 @@
+//! Values that are forced to satisfy an arbitrary predicate, like "even and within `0..=100`".
+//!
+//! Predicates are marker types that implement `Predicate`, so they can be combined with `And`, `Or` and `Not` from the `predicates` module at the type level.
+//!
+//! The bounded types are predicates as well, so `Refined<i64, BoundedI64<0, 10>>` is the same invariant as `BoundedI64<0, 10>`.
+//! Both convert into each other without losing out-of-bounds values, their errors convert into each other, and both are serialized the same way.
+//! Conversions between `Refined` and bounded types with other predicates or bounds check the value against the new invariant.
+//!
+//! `Refined` is generic over the value, so the strict variants and the integrations behind features are only implemented for the bounded types.
+
+pub mod predicates;
+
+use serde::{Deserialize, Serialize};
+use shrinkwraprs::Shrinkwrap;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// An invariant that the values of a `Refined` type have to satisfy.
+///
+/// ```
+/// use bounded_types::{Predicate, Refined};
+/// use std::fmt::Formatter;
+///
+/// struct Lowercase;
+/// impl Predicate<String> for Lowercase {
+///     fn test(value: &String) -> bool {
+///         value.chars().all(char::is_lowercase)
+///     }
+///     fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "lowercase")
+///     }
+/// }
+///
+/// let name: Refined<String, Lowercase> = String::from("Alice").into();
+/// assert_eq!((*name).as_ref().unwrap_err().to_string(), "value Alice doesn't satisfy lowercase");
+/// ```
+pub trait Predicate<T> {
+    /// Returns whether the value satisfies the predicate.
+    fn test(value: &T) -> bool;
+
+    /// Describes the predicate, for error messages.
+    /// # Errors
+    /// Fails if the formatter fails.
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result;
+}
+
+/// The error that is returned when you attempt to assign a value that doesn't satisfy the predicate to a `Refined` type.
+///
+/// It is serialized as the value, like the errors of the bounded types.
+#[derive(Shrinkwrap, Serialize, Deserialize)]
+#[serde(transparent)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct RefinementError<T, P>(#[shrinkwrap(main_field)] T, #[serde(skip)] PhantomData<P>);
+
+impl<T, P> RefinementError<T, P> {
+    /// Wraps a value that doesn't satisfy the predicate.
+    pub(crate) fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<T: Copy, P> RefinementError<T, P> {
+    /// Returns the value that was attempted to be passed.
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.0
+    }
+}
+
+impl<T: Clone, P> Clone for RefinementError<T, P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: Copy, P> Copy for RefinementError<T, P> {}
+
+impl<T: Debug, P> Debug for RefinementError<T, P> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("RefinementError").field("value", &self.0).finish()
+    }
+}
+
+impl<T: Display, P: Predicate<T>> Display for RefinementError<T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value {} doesn't satisfy ", self.0)?;
+        P::describe(f)
+    }
+}
+
+impl<T: Debug + Display, P: Predicate<T>> std::error::Error for RefinementError<T, P> {}
+
+/// An element that is forced to satisfy the predicate `P`.
+///
+/// It behaves like the bounded types: values that don't satisfy the predicate are stored as errors, and comparisons with them always return `false`.
+#[derive(Shrinkwrap, Serialize, Deserialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct Refined<T, P>(Result<T, RefinementError<T, P>>);
+
+impl<T: Copy, P> Refined<T, P> {
+    /// Returns the value stored in the struct, but overrides the predicate check.
+    #[must_use]
+    pub fn unchecked(&self) -> T {
+        match &self.0 {
+            Ok(val) => *val,
+            Err(err) => err.value(),
+        }
+    }
+}
+
+impl<T, P: Predicate<T>> Refined<T, P> {
+    /// Function that returns whether a value satisfies the predicate.
+    #[must_use]
+    pub fn is_in_bounds(val: &T) -> bool {
+        P::test(val)
+    }
+}
+
+impl<T, P: Predicate<T>> From<T> for Refined<T, P> {
+    fn from(value: T) -> Self {
+        if P::test(&value) {
+            Self(Ok(value))
+        } else {
+            Self(Err(RefinementError::new(value)))
+        }
+    }
+}
+
+impl<T: Clone, P> Clone for Refined<T, P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Copy, P> Copy for Refined<T, P> {}
+
+impl<T: Debug, P> Debug for Refined<T, P> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_tuple("Refined").field(&self.0).finish()
+    }
+}
+
+impl<T: Display + Debug, P> Display for Refined<T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(val) => write!(f, "{val}"),
+            Err(err) => write!(f, "{err:?}"),
+        }
+    }
+}
+
+impl<T: FromStr, P: Predicate<T>> FromStr for Refined<T, P> {
+    type Err = T::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(Self::from)
+    }
+}
+
+impl<T: PartialEq, P> PartialEq<T> for Refined<T, P> {
+    // will throw false if self doesn't satisfy the predicate
+    fn eq(&self, other: &T) -> bool {
+        match &self.0 {
+            Ok(val) => val == other,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T: PartialOrd, P> PartialOrd<T> for Refined<T, P> {
+    // will throw false if self doesn't satisfy the predicate
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.0.as_ref().ok()?.partial_cmp(other)
+    }
+}
+
+impl<T: PartialEq, P, Q> PartialEq<Refined<T, Q>> for Refined<T, P> {
+    // will throw false if either value doesn't satisfy its predicate
+    fn eq(&self, other: &Refined<T, Q>) -> bool {
+        match &other.0 {
+            Ok(other_val) => self == other_val,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T: PartialOrd, P, Q> PartialOrd<Refined<T, Q>> for Refined<T, P> {
+    fn partial_cmp(&self, other: &Refined<T, Q>) -> Option<Ordering> {
+        self.partial_cmp(other.0.as_ref().ok()?)
+    }
+}
+
+/// Implements `Predicate` for a bounded type, and the conversions between its error and the error of `Refined` with it as predicate.
+macro_rules! derive_predicate {
+    ( $type: ident, $error: ident, $bound:ty, $int:ty; $range: literal ) => {
+        /// Satisfied by the values that are within the bounds.
+        impl<const MIN: $bound, const MAX: $bound> crate::Predicate<$int> for $type<MIN, MAX> {
+            fn test(value: &$int) -> bool {
+                Self::is_in_bounds(value)
+            }
+
+            fn describe(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, $range, MIN, MAX)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> From<crate::RefinementError<$int, $type<MIN, MAX>>> for $error<MIN, MAX> {
+            fn from(other: crate::RefinementError<$int, $type<MIN, MAX>>) -> Self {
+                Self(other.value())
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> From<$error<MIN, MAX>> for crate::RefinementError<$int, $type<MIN, MAX>> {
+            fn from(other: $error<MIN, MAX>) -> Self {
+                Self::new(other.value())
+            }
+        }
+    };
+}
+
+/// Derives the conversions between a bounded type and `Refined`, and the comparisons of its backing integer with `Refined`.
+macro_rules! derive_refined {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        /// Converts into a refined type. The value is checked against the predicate.
+        impl<P: crate::Predicate<$int>, const MIN: $bound, const MAX: $bound> From<$type<MIN, MAX>> for crate::Refined<$int, P> {
+            fn from(other: $type<MIN, MAX>) -> Self {
+                Self::from(other.unchecked())
+            }
+        }
+
+        /// Converts from a refined type. The value is checked against the bounds.
+        impl<P, const MIN: $bound, const MAX: $bound> From<crate::Refined<$int, P>> for $type<MIN, MAX> {
+            fn from(other: crate::Refined<$int, P>) -> Self {
+                Self::from(other.unchecked())
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<P> PartialEq<crate::Refined<$int, P>> for $int {
+            fn eq(&self, other: &crate::Refined<$int, P>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<P> PartialOrd<crate::Refined<$int, P>> for $int {
+            fn partial_cmp(&self, other: &crate::Refined<$int, P>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
@@ -0,0 +1,129 @@
+//! The predicates that come with the crate, and the combinators to build new ones from them.
+//!
+//! They live in their own module, since names like `Not` would shadow the traits of the prelude.
+
+use super::Predicate;
+use std::convert::TryInto;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+/// Returns the absolute value of an integer, which decides whether it is a multiple of another one.
+fn magnitude<T: Copy + TryInto<i128> + TryInto<u128>>(value: T) -> u128 {
+    match TryInto::<i128>::try_into(value) {
+        Ok(value) => value.unsigned_abs(),
+        // only integers that are larger than i128::MAX don't fit
+        Err(_) => TryInto::<u128>::try_into(value).unwrap_or(u128::MAX),
+    }
+}
+
+/// Satisfied by integers within the inclusive range `MIN..=MAX`.
+///
+/// The bounds are `i128`, so `u128` values above `i128::MAX` never satisfy it. Use a bounded type like `BoundedU128<MIN, MAX>` as predicate instead, whose bounds have the type of the value.
+#[derive(Debug, Copy, Clone)]
+pub struct InRange<const MIN: i128, const MAX: i128>;
+
+impl<T: Copy + TryInto<i128>, const MIN: i128, const MAX: i128> Predicate<T> for InRange<MIN, MAX> {
+    fn test(value: &T) -> bool {
+        (*value).try_into().is_ok_and(|value: i128| (MIN..=MAX).contains(&value))
+    }
+
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{MIN}..={MAX}")
+    }
+}
+
+/// Satisfied by even integers.
+#[derive(Debug, Copy, Clone)]
+pub struct Even;
+
+impl<T: Copy + TryInto<i128> + TryInto<u128>> Predicate<T> for Even {
+    fn test(value: &T) -> bool {
+        magnitude(*value) % 2 == 0
+    }
+
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "even")
+    }
+}
+
+/// Satisfied by integers that are a multiple of `N`. Only 0 is a multiple of 0.
+#[derive(Debug, Copy, Clone)]
+pub struct MultipleOf<const N: i128>;
+
+impl<T: Copy + TryInto<i128> + TryInto<u128>, const N: i128> Predicate<T> for MultipleOf<N> {
+    fn test(value: &T) -> bool {
+        match N.unsigned_abs() {
+            0 => magnitude(*value) == 0,
+            n => magnitude(*value) % n == 0,
+        }
+    }
+
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a multiple of {N}")
+    }
+}
+
+/// Satisfied by values that aren't equal to their default, which is 0 for numbers.
+#[derive(Debug, Copy, Clone)]
+pub struct NonZero;
+
+impl<T: Default + PartialEq> Predicate<T> for NonZero {
+    fn test(value: &T) -> bool {
+        *value != T::default()
+    }
+
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "non-zero")
+    }
+}
+
+/// Satisfied if both `A` and `B` are satisfied.
+#[derive(Debug, Copy, Clone)]
+pub struct And<A, B>(PhantomData<(A, B)>);
+
+impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for And<A, B> {
+    fn test(value: &T) -> bool {
+        A::test(value) && B::test(value)
+    }
+
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        A::describe(f)?;
+        write!(f, " and ")?;
+        B::describe(f)?;
+        write!(f, ")")
+    }
+}
+
+/// Satisfied if `A` or `B` is satisfied.
+#[derive(Debug, Copy, Clone)]
+pub struct Or<A, B>(PhantomData<(A, B)>);
+
+impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Or<A, B> {
+    fn test(value: &T) -> bool {
+        A::test(value) || B::test(value)
+    }
+
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        A::describe(f)?;
+        write!(f, " or ")?;
+        B::describe(f)?;
+        write!(f, ")")
+    }
+}
+
+/// Satisfied if `A` isn't satisfied.
+#[derive(Debug, Copy, Clone)]
+pub struct Not<A>(PhantomData<A>);
+
+impl<T, A: Predicate<T>> Predicate<T> for Not<A> {
+    fn test(value: &T) -> bool {
+        !A::test(value)
+    }
+
+    fn describe(f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not ")?;
+        A::describe(f)
+    }
+}
@@ -0,0 +1,217 @@
+//! Bounded types with a narrower "normal" range inside the hard bounds, like telemetry values that are valid but worth a warning.
+//!
+//! A value is allowed if it is within `MIN..=MAX`, and normal if it is also within `WARN_LO..=WARN_HI`.
+
+/// The side of the normal range that a value lies on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Warning {
+    /// Below `WARN_LO`.
+    Low,
+    /// Above `WARN_HI`.
+    High,
+}
+
+/// The classification of a value of a soft-bounded type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Classification {
+    /// Within the soft bounds.
+    Normal,
+    /// Within the hard bounds, but outside of the soft bounds.
+    Warning(Warning),
+    /// Outside of the hard bounds.
+    OutOfBounds,
+}
+
+/// Derives the traits that define the relation of a soft-bounded type to other numeric types. Conversions and comparisons are deduced from the type with the hard bounds.
+macro_rules! derive_soft_numeric_traits {
+    ( $soft: ident, $type: ident, $bound:ty; $( $numeric:ty ),* ) => {
+        $(
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> From<$numeric> for $soft<MIN, MAX, WARN_LO, WARN_HI> {
+            fn from(other: $numeric) -> Self {
+                Self::new($type::<MIN, MAX>::from(other).0)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> PartialEq<$numeric> for $soft<MIN, MAX, WARN_LO, WARN_HI> {
+            // will throw false if self is out of bounds
+            fn eq(&self, other: &$numeric) -> bool {
+                self.hard() == *other
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> PartialOrd<$numeric> for $soft<MIN, MAX, WARN_LO, WARN_HI> {
+            // will throw false if self is out of bounds
+            fn partial_cmp(&self, other: &$numeric) -> Option<Ordering> {
+                self.hard().partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> PartialEq<$soft<MIN, MAX, WARN_LO, WARN_HI>> for $numeric {
+            fn eq(&self, other: &$soft<MIN, MAX, WARN_LO, WARN_HI>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> PartialOrd<$soft<MIN, MAX, WARN_LO, WARN_HI>> for $numeric {
+            fn partial_cmp(&self, other: &$soft<MIN, MAX, WARN_LO, WARN_HI>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )*
+    };
+}
+
+/// Generates the soft-bounded variant of a bounded type. It is named like the bounded type with a `Soft` prefix, and shares its error type.
+macro_rules! generate_soft {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        paste::paste! {
+        #[derive(Shrinkwrap, Debug, Copy, Clone)]
+        #[derive(Serialize, Deserialize)]
+        #[doc="An `" $int "` element that is forced to be within the inclusive range `MIN..=MAX`, and is classified as normal if it is also within `WARN_LO..=WARN_HI`."]
+        pub struct [<Soft $type>]<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound>(
+            Result<$int, OutOfBoundsError<MIN, MAX>>,
+        );
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI> {
+            /// Wraps a checked value. Only compiles if the soft bounds are within the hard bounds.
+            fn new(inner: Result<$int, OutOfBoundsError<MIN, MAX>>) -> Self {
+                const { assert!(MIN <= WARN_LO && WARN_LO <= WARN_HI && WARN_HI <= MAX, "the soft bounds have to be within the hard bounds") };
+                Self(inner)
+            }
+
+            /// Returns the numeric value stored in the struct, but overrides the bounds check.
+            #[must_use]
+            pub fn unchecked(&self) -> $int {
+                match &self.0 {
+                    Ok(val) => *val,
+                    Err(err) => err.value(),
+                }
+            }
+
+            /// Function that returns whether a value is within the hard bounds.
+            pub fn is_in_bounds(val: &impl PartialOrd<$int>) -> bool {
+                $type::<MIN, MAX>::is_in_bounds(val)
+            }
+
+            /// Classifies a value against the hard and soft bounds. Only compiles if the soft bounds are within the hard bounds.
+            #[must_use]
+            pub fn classify_value(val: $int) -> crate::Classification {
+                // also checked here, since deserialized values don't pass through `new`
+                const { assert!(MIN <= WARN_LO && WARN_LO <= WARN_HI && WARN_HI <= MAX, "the soft bounds have to be within the hard bounds") };
+                if !Self::is_in_bounds(&val) {
+                    crate::Classification::OutOfBounds
+                } else if val < WARN_LO {
+                    crate::Classification::Warning(crate::Warning::Low)
+                } else if val > WARN_HI {
+                    crate::Classification::Warning(crate::Warning::High)
+                } else {
+                    crate::Classification::Normal
+                }
+            }
+
+            /// Classifies the stored value against the hard and soft bounds.
+            #[must_use]
+            pub fn classify(&self) -> crate::Classification {
+                Self::classify_value(self.unchecked())
+            }
+
+            /// Returns whether the stored value is within the soft bounds.
+            #[must_use]
+            pub fn is_normal(&self) -> bool {
+                self.classify() == crate::Classification::Normal
+            }
+
+            /// Converts the value into the type with the same hard bounds.
+            #[must_use]
+            pub fn hard(self) -> $type<MIN, MAX> {
+                $type(self.0)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> std::fmt::Display for [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match &self.0 {
+                    Ok(val) => write!(f, "{}", val),
+                    Err(err) => write!(f, "{:?}", err),
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound> FromStr for [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI> {
+            type Err = <$bound as FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <$bound>::from_str(s).map(Self::from)
+            }
+        }
+
+        /// Converts into a type without soft bounds. The value is checked against the new bounds.
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            From<[<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>> for $type<OTHER_MIN, OTHER_MAX>
+        {
+            fn from(other: [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>) -> Self {
+                Self::from(other.unchecked())
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound,
+            const OTHER_MIN: $bound, const OTHER_MAX: $bound, const OTHER_WARN_LO: $bound, const OTHER_WARN_HI: $bound>
+            PartialEq<[<Soft $type>]<OTHER_MIN, OTHER_MAX, OTHER_WARN_LO, OTHER_WARN_HI>> for [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>
+        {
+            // will throw false if either side is out of bounds
+            fn eq(&self, other: &[<Soft $type>]<OTHER_MIN, OTHER_MAX, OTHER_WARN_LO, OTHER_WARN_HI>) -> bool {
+                self.hard() == other.hard()
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound,
+            const OTHER_MIN: $bound, const OTHER_MAX: $bound, const OTHER_WARN_LO: $bound, const OTHER_WARN_HI: $bound>
+            PartialOrd<[<Soft $type>]<OTHER_MIN, OTHER_MAX, OTHER_WARN_LO, OTHER_WARN_HI>> for [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>
+        {
+            // will throw false if either side is out of bounds
+            fn partial_cmp(&self, other: &[<Soft $type>]<OTHER_MIN, OTHER_MAX, OTHER_WARN_LO, OTHER_WARN_HI>) -> Option<Ordering> {
+                self.hard().partial_cmp(&other.hard())
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialEq<$type<OTHER_MIN, OTHER_MAX>> for [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>
+        {
+            // will throw false if either side is out of bounds
+            fn eq(&self, other: &$type<OTHER_MIN, OTHER_MAX>) -> bool {
+                self.hard() == *other
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialOrd<$type<OTHER_MIN, OTHER_MAX>> for [<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>
+        {
+            // will throw false if either side is out of bounds
+            fn partial_cmp(&self, other: &$type<OTHER_MIN, OTHER_MAX>) -> Option<Ordering> {
+                self.hard().partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialEq<[<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>> for $type<OTHER_MIN, OTHER_MAX>
+        {
+            fn eq(&self, other: &[<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const MIN: $bound, const MAX: $bound, const WARN_LO: $bound, const WARN_HI: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialOrd<[<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>> for $type<OTHER_MIN, OTHER_MAX>
+        {
+            fn partial_cmp(&self, other: &[<Soft $type>]<MIN, MAX, WARN_LO, WARN_HI>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+
+        derive_soft_numeric_traits!([<Soft $type>], $type, $bound; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        }
+    };
+}
@@ -0,0 +1,148 @@
+//! Support for storing bounded values in SQL databases through `rusqlite` and `postgres-types`.
+//!
+//! Only in-bounds values can be written. Reading an out-of-bounds value fails with an error type named like the bounded type with a `FromSqlError` suffix, like `BoundedI64FromSqlError`.
+//! It is returned for values that don't even fit into the backing integer as well, so the range of the column is always part of the error message, and the error can always be downcast to the same type.
+
+/// Generates the error type that is returned when a database value can't be read into a bounded type, along with the conversion that both backends use.
+macro_rules! derive_from_sql_error {
+    ( $type: ident, $bound:ty, $int:ty; $range: literal ) => {
+        paste::paste! {
+        #[cfg(any(feature = "rusqlite", feature = "postgres"))]
+        #[derive(Copy, Clone)]
+        #[doc="The error that is returned when an out-of-bounds database value is read into a `" $type "`. The value doesn't need to fit into `" $int "`."]
+        pub struct [<$type FromSqlError>]<const MIN: $bound, const MAX: $bound>(i64);
+
+        #[cfg(any(feature = "rusqlite", feature = "postgres"))]
+        impl<const MIN: $bound, const MAX: $bound> [<$type FromSqlError>]<MIN, MAX> {
+            /// Returns the value that was read from the database.
+            #[must_use]
+            pub fn value(&self) -> i64 {
+                self.0
+            }
+
+            /// Returns the lower bound.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn min_allowed(&self) -> $bound {
+                MIN
+            }
+
+            /// Returns the upper bound.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn max_allowed(&self) -> $bound {
+                MAX
+            }
+        }
+
+        #[cfg(any(feature = "rusqlite", feature = "postgres"))]
+        impl<const MIN: $bound, const MAX: $bound> Debug for [<$type FromSqlError>]<MIN, MAX> {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt.debug_struct(stringify!([<$type FromSqlError>]))
+                    .field("value", &self.value())
+                    .field("min_allowed", &self.min_allowed())
+                    .field("max_allowed", &self.max_allowed())
+                    .finish()
+            }
+        }
+
+        #[cfg(any(feature = "rusqlite", feature = "postgres"))]
+        impl<const MIN: $bound, const MAX: $bound> std::fmt::Display for [<$type FromSqlError>]<MIN, MAX> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!("value {} is out of bounds, allowed range is ", $range), self.value(), MIN, MAX)
+            }
+        }
+
+        #[cfg(any(feature = "rusqlite", feature = "postgres"))]
+        impl<const MIN: $bound, const MAX: $bound> std::error::Error for [<$type FromSqlError>]<MIN, MAX> {}
+
+        #[cfg(any(feature = "rusqlite", feature = "postgres"))]
+        impl<const MIN: $bound, const MAX: $bound> $type<MIN, MAX> {
+            /// Converts a value that was read from the database, rejecting values that are out of bounds or don't fit into $int.
+            #[allow(clippy::useless_conversion)]
+            fn from_sql_value(raw: i64) -> Result<Self, [<$type FromSqlError>]<MIN, MAX>> {
+                match <$int>::try_from(raw) {
+                    Ok(val) if Self::is_in_bounds(&val) => Ok(Self(Ok(val))),
+                    _ => Err([<$type FromSqlError>](raw)),
+                }
+            }
+        }
+        }
+    };
+}
+
+/// Implements `rusqlite::ToSql` and `rusqlite::FromSql` for a bounded type. Values are stored as `INTEGER` values.
+macro_rules! derive_rusqlite {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        #[cfg(feature = "rusqlite")]
+        impl<const MIN: $bound, const MAX: $bound> ::rusqlite::ToSql for $type<MIN, MAX> {
+            #[allow(clippy::useless_conversion)]
+            fn to_sql(&self) -> ::rusqlite::Result<::rusqlite::types::ToSqlOutput<'_>> {
+                let val = self.0.map_err(|err| ::rusqlite::Error::ToSqlConversionFailure(Box::new(err)))?;
+                let val = i64::try_from(val).map_err(|err| ::rusqlite::Error::ToSqlConversionFailure(Box::new(err)))?;
+                Ok(val.into())
+            }
+        }
+
+        #[cfg(feature = "rusqlite")]
+        impl<const MIN: $bound, const MAX: $bound> ::rusqlite::types::FromSql for $type<MIN, MAX> {
+            fn column_result(value: ::rusqlite::types::ValueRef<'_>) -> ::rusqlite::types::FromSqlResult<Self> {
+                let raw = <i64 as ::rusqlite::types::FromSql>::column_result(value)?;
+                Self::from_sql_value(raw).map_err(|err| ::rusqlite::types::FromSqlError::Other(Box::new(err)))
+            }
+        }
+    };
+}
+
+/// Implements `postgres_types::ToSql` and `postgres_types::FromSql` for a bounded type. Values can be stored in `INT2`, `INT4` and `INT8` columns.
+macro_rules! derive_postgres {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        #[cfg(feature = "postgres")]
+        impl<const MIN: $bound, const MAX: $bound> ::postgres_types::ToSql for $type<MIN, MAX> {
+            #[allow(clippy::useless_conversion)]
+            fn to_sql(
+                &self,
+                ty: &::postgres_types::Type,
+                out: &mut ::bytes::BytesMut,
+            ) -> Result<::postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                let val = self.0?;
+                match *ty {
+                    ::postgres_types::Type::INT2 => ::postgres_types::ToSql::to_sql(&i16::try_from(val)?, ty, out),
+                    ::postgres_types::Type::INT4 => ::postgres_types::ToSql::to_sql(&i32::try_from(val)?, ty, out),
+                    _ => ::postgres_types::ToSql::to_sql(&i64::try_from(val)?, ty, out),
+                }
+            }
+
+            fn accepts(ty: &::postgres_types::Type) -> bool {
+                matches!(
+                    *ty,
+                    ::postgres_types::Type::INT2 | ::postgres_types::Type::INT4 | ::postgres_types::Type::INT8
+                )
+            }
+
+            ::postgres_types::to_sql_checked!();
+        }
+
+        #[cfg(feature = "postgres")]
+        impl<'a, const MIN: $bound, const MAX: $bound> ::postgres_types::FromSql<'a> for $type<MIN, MAX> {
+            fn from_sql(
+                ty: &::postgres_types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                let raw = match *ty {
+                    ::postgres_types::Type::INT2 => i64::from(<i16 as ::postgres_types::FromSql<'_>>::from_sql(ty, raw)?),
+                    ::postgres_types::Type::INT4 => i64::from(<i32 as ::postgres_types::FromSql<'_>>::from_sql(ty, raw)?),
+                    _ => <i64 as ::postgres_types::FromSql<'_>>::from_sql(ty, raw)?,
+                };
+                Ok(Self::from_sql_value(raw)?)
+            }
+
+            fn accepts(ty: &::postgres_types::Type) -> bool {
+                matches!(
+                    *ty,
+                    ::postgres_types::Type::INT2 | ::postgres_types::Type::INT4 | ::postgres_types::Type::INT8
+                )
+            }
+        }
+    };
+}
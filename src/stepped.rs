@@ -0,0 +1,270 @@
+//! Bounded types whose values also have to be aligned to a step, like buffer sizes that are multiples of 64.
+//!
+//! A value is allowed if it is within `MIN..=MAX` and `(value - MIN) % STEP == 0`.
+
+/// Derives the traits that define the relation of a stepped type to other numeric types. Comparisons are deduced from the bounded type over all values of the backing integer.
+macro_rules! derive_stepped_numeric_traits {
+    ( $stepped: ident, $error: ident, $type: ident, $bound:ty, $int:ty; $( $numeric:ty ),* ) => {
+        $(
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> From<$numeric> for $stepped<MIN, MAX, STEP> {
+            // numbers that can't be cast as $int are stored as $int::MIN or $int::MAX, whichever is closer
+            fn from(other: $numeric) -> Self {
+                let unbounded = $type::<{ <$int>::MIN }, { <$int>::MAX }>::from(other);
+                match *unbounded {
+                    Ok(val) if Self::is_in_bounds(&val) => Self(Ok(val)),
+                    _ => Self(Err($error(unbounded.unchecked()))),
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> PartialEq<$numeric> for $stepped<MIN, MAX, STEP> {
+            // will throw false if self is out of bounds
+            fn eq(&self, other: &$numeric) -> bool {
+                self.as_unbounded().map_or(false, |val| val == *other)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> PartialOrd<$numeric> for $stepped<MIN, MAX, STEP> {
+            // will throw false if self is out of bounds
+            fn partial_cmp(&self, other: &$numeric) -> Option<Ordering> {
+                self.as_unbounded()?.partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> PartialEq<$stepped<MIN, MAX, STEP>> for $numeric {
+            fn eq(&self, other: &$stepped<MIN, MAX, STEP>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> PartialOrd<$stepped<MIN, MAX, STEP>> for $numeric {
+            fn partial_cmp(&self, other: &$stepped<MIN, MAX, STEP>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )*
+    };
+}
+
+/// Generates the stepped variant of a bounded type. It is named like the bounded type with a `Stepped` prefix.
+macro_rules! generate_stepped {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        paste::paste! {
+        #[derive(Shrinkwrap, Copy, Clone)]
+        #[derive(Serialize, Deserialize)]
+        /// The error that is returned when you attempt to assign an out-of-bounds or misaligned value to a stepped type.
+        pub struct [<Stepped $type OutOfBoundsError>]<const MIN: $bound, const MAX: $bound, const STEP: $bound>($int);
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> [<Stepped $type OutOfBoundsError>]<MIN, MAX, STEP> {
+            /// Returns the value that was attempted to be passed.
+            #[must_use]
+            pub fn value(&self) -> $int {
+                self.0
+            }
+
+            /// Returns the smallest allowed value.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn min_allowed(&self) -> $bound {
+                MIN
+            }
+
+            /// Returns the largest allowed value.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn max_allowed(&self) -> $bound {
+                MAX
+            }
+
+            /// Returns the step.
+            #[must_use]
+            #[allow(clippy::unused_self)]
+            pub fn step(&self) -> $bound {
+                STEP
+            }
+
+            /// Returns whether the value is within `MIN..=MAX`, but isn't aligned to the step.
+            #[must_use]
+            pub fn is_misaligned(&self) -> bool {
+                $type::<MIN, MAX>::is_in_bounds(&self.0)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> Debug for [<Stepped $type OutOfBoundsError>]<MIN, MAX, STEP> {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt.debug_struct(stringify!([<Stepped $type OutOfBoundsError>]))
+                    .field("value", &self.value())
+                    .field("min_allowed", &self.min_allowed())
+                    .field("max_allowed", &self.max_allowed())
+                    .field("step", &self.step())
+                    .finish()
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> std::fmt::Display for [<Stepped $type OutOfBoundsError>]<MIN, MAX, STEP> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if self.is_misaligned() {
+                    write!(f, "value {} is misaligned, it must be {} plus a multiple of {}", self.value(), MIN, STEP)
+                } else {
+                    write!(f, "value {} is out of bounds, allowed range is {}..={}", self.value(), MIN, MAX)
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> std::error::Error for [<Stepped $type OutOfBoundsError>]<MIN, MAX, STEP> {}
+
+        #[derive(Shrinkwrap, Debug, Copy, Clone)]
+        #[derive(Serialize, Deserialize)]
+        #[doc="An `" $int "` element that is forced to be within the inclusive range `MIN..=MAX`, and to be `MIN` plus a multiple of `STEP`."]
+        pub struct [<Stepped $type>]<const MIN: $bound, const MAX: $bound, const STEP: $bound>(Result<$int, [<Stepped $type OutOfBoundsError>]<MIN, MAX, STEP>>);
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> [<Stepped $type>]<MIN, MAX, STEP> {
+            /// Returns the numeric value stored in the struct, but overrides the bounds check.
+            #[must_use]
+            pub fn unchecked(&self) -> $int {
+                match &self.0 {
+                    Ok(val) => *val,
+                    Err(err) => err.value(),
+                }
+            }
+
+            /// Returns the distance of a value from `MIN`, and the step. The casts are lossless, because both are non-negative.
+            #[allow(trivial_numeric_casts, clippy::cast_lossless, clippy::cast_sign_loss)]
+            fn offset(val: $int) -> (u128, u128) {
+                const { assert!(STEP > 0, "the step has to be positive") };
+                (val.abs_diff(MIN) as u128, STEP as u128)
+            }
+
+            /// Returns `MIN` plus an offset that keeps the result within `MIN..=MAX`. The wrapping addition is exact, since the result fits into $int.
+            #[allow(trivial_numeric_casts, clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            fn from_offset(offset: u128) -> Self {
+                Self(Ok(MIN.wrapping_add(offset as $int)))
+            }
+
+            /// Function that returns whether a value is within the bounds and aligned to the step.
+            #[must_use]
+            pub fn is_in_bounds(val: &$int) -> bool {
+                let (offset, step) = Self::offset(*val);
+                $type::<MIN, MAX>::is_in_bounds(val) && offset % step == 0
+            }
+
+            /// Converts a value into a stepped value by rounding it to the nearest step and clamping it to the bounds. Ties are rounded up.
+            /// Out-of-bounds values are only returned if `MIN` is larger than `MAX`.
+            #[must_use]
+            pub fn round_to_step(val: $int) -> Self {
+                if MIN > MAX {
+                    return Self::from(val);
+                }
+                if val <= MIN {
+                    return Self::from_offset(0);
+                }
+                let (max_offset, step) = Self::offset(MAX);
+                let last_offset = max_offset / step * step;
+                let (offset, _) = Self::offset(val);
+                let steps = offset / step + u128::from(offset % step >= step - offset % step);
+                Self::from_offset(steps.checked_mul(step).map_or(last_offset, |offset| offset.min(last_offset)))
+            }
+
+            /// Returns an iterator over all allowed values, in ascending order.
+            pub fn values() -> impl DoubleEndedIterator<Item = Self> {
+                let (max_offset, step) = Self::offset(MAX);
+                let last_index = if MIN > MAX { None } else { Some(max_offset / step) };
+                last_index
+                    .into_iter()
+                    .flat_map(move |last_index| (0..=last_index).map(move |index| Self::from_offset(index * step)))
+            }
+
+            /// Returns the value as a bounded type over all values of the backing integer, so that it can be compared.
+            fn as_unbounded(&self) -> Option<$type<{ <$int>::MIN }, { <$int>::MAX }>> {
+                self.0.as_ref().ok().map(|val| $type::from(*val))
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> std::fmt::Display for [<Stepped $type>]<MIN, MAX, STEP> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match &self.0 {
+                    Ok(val) => write!(f, "{}", val),
+                    Err(err) => write!(f, "{:?}", err),
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound> FromStr for [<Stepped $type>]<MIN, MAX, STEP> {
+            type Err = <$bound as FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <$bound>::from_str(s).map(Self::from)
+            }
+        }
+
+        /// Converts into a bounded type without a step. The value is checked against the new bounds.
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound> From<[<Stepped $type>]<MIN, MAX, STEP>>
+            for $type<OTHER_MIN, OTHER_MAX>
+        {
+            fn from(other: [<Stepped $type>]<MIN, MAX, STEP>) -> Self {
+                Self::from(other.unchecked())
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound, const OTHER_STEP: $bound>
+            PartialEq<[<Stepped $type>]<OTHER_MIN, OTHER_MAX, OTHER_STEP>> for [<Stepped $type>]<MIN, MAX, STEP>
+        {
+            // will throw false if either side is out of bounds
+            fn eq(&self, other: &[<Stepped $type>]<OTHER_MIN, OTHER_MAX, OTHER_STEP>) -> bool {
+                match (self.as_unbounded(), other.as_unbounded()) {
+                    (Some(self_val), Some(other_val)) => self_val == other_val,
+                    _ => false,
+                }
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound, const OTHER_STEP: $bound>
+            PartialOrd<[<Stepped $type>]<OTHER_MIN, OTHER_MAX, OTHER_STEP>> for [<Stepped $type>]<MIN, MAX, STEP>
+        {
+            // will throw false if either side is out of bounds
+            fn partial_cmp(&self, other: &[<Stepped $type>]<OTHER_MIN, OTHER_MAX, OTHER_STEP>) -> Option<Ordering> {
+                self.as_unbounded()?.partial_cmp(&other.as_unbounded()?)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialEq<$type<OTHER_MIN, OTHER_MAX>> for [<Stepped $type>]<MIN, MAX, STEP>
+        {
+            // will throw false if either side is out of bounds
+            fn eq(&self, other: &$type<OTHER_MIN, OTHER_MAX>) -> bool {
+                self.as_unbounded().map_or(false, |val| val == *other)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialOrd<$type<OTHER_MIN, OTHER_MAX>> for [<Stepped $type>]<MIN, MAX, STEP>
+        {
+            // will throw false if either side is out of bounds
+            fn partial_cmp(&self, other: &$type<OTHER_MIN, OTHER_MAX>) -> Option<Ordering> {
+                self.as_unbounded()?.partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialEq<[<Stepped $type>]<MIN, MAX, STEP>> for $type<OTHER_MIN, OTHER_MAX>
+        {
+            fn eq(&self, other: &[<Stepped $type>]<MIN, MAX, STEP>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const MIN: $bound, const MAX: $bound, const STEP: $bound, const OTHER_MIN: $bound, const OTHER_MAX: $bound>
+            PartialOrd<[<Stepped $type>]<MIN, MAX, STEP>> for $type<OTHER_MIN, OTHER_MAX>
+        {
+            fn partial_cmp(&self, other: &[<Stepped $type>]<MIN, MAX, STEP>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+
+        derive_stepped_numeric_traits!([<Stepped $type>], [<Stepped $type OutOfBoundsError>], $type, $bound, $int; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        }
+    };
+}
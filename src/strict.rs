@@ -0,0 +1,180 @@
+//! Strict variants of the bounded types, which can only hold in-bounds values.
+//!
+//! Since there is no out-of-bounds state, these types have total equality and ordering, so they implement `Eq`, `Ord` and `Hash` and can be used as map keys and sort keys.
+
+/// Derives the traits that define the relation of a strict type to other numeric types. Comparisons are deduced from the lenient type.
+macro_rules! derive_strict_numeric_traits {
+    ( $strict: ident, $type: ident, $error: ident, $bound:ty, $int:ty; $( $numeric:ty ),* ) => {
+        $(
+        impl<const MIN: $bound, const MAX: $bound> TryFrom<$numeric> for $strict<MIN, MAX> {
+            type Error = $error<MIN, MAX>;
+            fn try_from(other: $numeric) -> Result<Self, Self::Error> {
+                $type::<MIN, MAX>::from(other).0.map(Self)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> PartialEq<$numeric> for $strict<MIN, MAX> {
+            fn eq(&self, other: &$numeric) -> bool {
+                $type::from(*self) == *other
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> PartialOrd<$numeric> for $strict<MIN, MAX> {
+            fn partial_cmp(&self, other: &$numeric) -> Option<Ordering> {
+                $type::from(*self).partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<const MIN: $bound, const MAX: $bound> PartialEq<$strict<MIN, MAX>> for $numeric {
+            fn eq(&self, other: &$strict<MIN, MAX>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<const MIN: $bound, const MAX: $bound> PartialOrd<$strict<MIN, MAX>> for $numeric {
+            fn partial_cmp(&self, other: &$strict<MIN, MAX>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )*
+    };
+}
+
+/// Derives methods that convert a strict type into primitive integers. Each method only compiles if all allowed values fit into the primitive, so it can't fail.
+macro_rules! derive_strict_primitive_conversions {
+    ( $strict: ident, $type: ident, $bound:ty, $int:ty; $( $prim:ident ),* ) => {
+        paste::paste! {
+        impl<const MIN: $bound, const MAX: $bound> $strict<MIN, MAX> {
+            /// Returns whether `val` is within `target_min..=target_max`. The casts are lossless, because negative values are only cast to `i128`, and non-negative ones to `u128`.
+            #[allow(
+                trivial_numeric_casts,
+                clippy::cast_lossless,
+                clippy::cast_possible_wrap,
+                clippy::cast_sign_loss
+            )]
+            const fn fits(val: $bound, target_min: i128, target_max: u128) -> bool {
+                let is_negative = <$bound>::MIN != 0 && (val as i128) < 0;
+                if is_negative {
+                    val as i128 >= target_min
+                } else {
+                    val as u128 <= target_max
+                }
+            }
+
+            $(
+            #[doc = "Converts the value into an `" $prim "`. This only compiles if all allowed values fit into `" $prim "`."]
+            #[must_use]
+            #[allow(
+                trivial_numeric_casts,
+                clippy::cast_possible_truncation,
+                clippy::cast_possible_wrap,
+                clippy::cast_sign_loss,
+                clippy::cast_lossless
+            )]
+            pub fn [<to_ $prim>](self) -> $prim {
+                const {
+                    assert!(
+                        match $type::<MIN, MAX>::allowed_range() {
+                            Some((first, last)) => {
+                                Self::fits(first, <$prim>::MIN as i128, <$prim>::MAX as u128)
+                                    && Self::fits(last, <$prim>::MIN as i128, <$prim>::MAX as u128)
+                            }
+                            None => true,
+                        },
+                        concat!("the bounds don't fit into ", stringify!($prim))
+                    )
+                };
+                self.0 as $prim
+            }
+            )*
+        }
+        }
+    };
+}
+
+/// Generates the strict variant of a bounded type. It is named like the lenient type with a `Strict` prefix.
+macro_rules! generate_strict {
+    ( $type: ident, $error: ident, $bound:ty, $int:ty; $doc: literal ) => {
+        paste::paste! {
+        #[derive(Shrinkwrap, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[doc="An `" $int "` element that is guaranteed to be within " $doc ". In contrast to `" $type "`, it can't hold out-of-bounds values, so it is `Eq`, `Ord` and `Hash`."]
+        pub struct [<Strict $type>]<const MIN: $bound, const MAX: $bound>($int);
+
+        impl<const MIN: $bound, const MAX: $bound> [<Strict $type>]<MIN, MAX> {
+            /// Returns the numeric value stored in the struct.
+            #[must_use]
+            pub fn get(self) -> $int {
+                self.0
+            }
+
+            /// Returns the value as `f64`, rounded to the nearest float if it can't be represented exactly.
+            #[must_use]
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64
+            }
+
+            /// Converts the value into a strict type with wider bounds. This only compiles if the new bounds contain the old bounds.
+            #[must_use]
+            pub fn widen<const NEW_MIN: $bound, const NEW_MAX: $bound>(self) -> [<Strict $type>]<NEW_MIN, NEW_MAX> {
+                const { assert!(NEW_MIN <= MIN && MAX <= NEW_MAX, "the new bounds have to contain the old bounds") };
+                [<Strict $type>](self.0)
+            }
+
+            /// Converts the value into a strict type with other (usually narrower) bounds.
+            /// # Errors
+            /// Fails if the value is outside of the new bounds.
+            pub fn narrow<const NEW_MIN: $bound, const NEW_MAX: $bound>(self) -> Result<[<Strict $type>]<NEW_MIN, NEW_MAX>, $error<NEW_MIN, NEW_MAX>> {
+                $type::from(self).narrow().and_then($type::strict)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> $type<MIN, MAX> {
+            /// Converts the value into its strict variant.
+            /// # Errors
+            /// Fails if the value is out of bounds.
+            pub fn strict(self) -> Result<[<Strict $type>]<MIN, MAX>, $error<MIN, MAX>> {
+                self.0.map([<Strict $type>])
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> From<[<Strict $type>]<MIN, MAX>> for $type<MIN, MAX> {
+            fn from(other: [<Strict $type>]<MIN, MAX>) -> Self {
+                Self(Ok(other.0))
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> TryFrom<$type<MIN, MAX>> for [<Strict $type>]<MIN, MAX> {
+            type Error = $error<MIN, MAX>;
+            fn try_from(other: $type<MIN, MAX>) -> Result<Self, Self::Error> {
+                other.strict()
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> std::fmt::Display for [<Strict $type>]<MIN, MAX> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl<const MIN: $bound, const MAX: $bound> Serialize for [<Strict $type>]<MIN, MAX> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        /// Out-of-bounds values are rejected while deserializing.
+        impl<'de, const MIN: $bound, const MAX: $bound> Deserialize<'de> for [<Strict $type>]<MIN, MAX> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let val = <$int>::deserialize(deserializer)?;
+                Self::try_from(val).map_err(serde::de::Error::custom)
+            }
+        }
+
+        derive_strict_numeric_traits!([<Strict $type>], $type, $error, $bound, $int; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        derive_strict_primitive_conversions!([<Strict $type>], $type, $bound, $int; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        }
+    };
+}
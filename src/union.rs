@@ -0,0 +1,278 @@
+//! Bounded types with non-contiguous domains, like "any `i32` except 0" or "`1..=10` or `20..=30`".
+//!
+//! Const generics can't take slices, so the domain is described by a marker type that implements `Domain`.
+
+use std::ops::RangeInclusive;
+
+/// Describes the domain of a `UnionBounded` type: the union of `RANGES`, without the values in `EXCLUDED`.
+///
+/// ```
+/// use bounded_types::{Domain, UnionBoundedU16};
+/// use std::ops::RangeInclusive;
+///
+/// /// Unprivileged ports, without the block reserved for the X window system.
+/// struct Ports;
+/// impl Domain<u16> for Ports {
+///     const RANGES: &'static [RangeInclusive<u16>] = &[1024..=5999, 6064..=65535];
+/// }
+///
+/// let port: UnionBoundedU16<Ports> = 6000.into();
+/// assert_eq!(port.unwrap_err().nearest_range(), Some(1024..=5999));
+/// ```
+pub trait Domain<T: 'static> {
+    /// The inclusive ranges that make up the domain. They may overlap.
+    const RANGES: &'static [RangeInclusive<T>];
+    /// Single values that are excluded from the ranges.
+    const EXCLUDED: &'static [T] = &[];
+}
+
+/// Generates the type with a non-contiguous domain for a backing integer. It is named like the bounded type with a `Union` prefix, and its error type has an additional `OutOfBoundsError` suffix.
+macro_rules! generate_union {
+    ( $type: ident, $bound:ty, $int:ty ) => {
+        paste::paste! {
+        /// The error that is returned when you attempt to assign a value outside of the domain to a `UnionBounded` type.
+        #[derive(Shrinkwrap, Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct [<Union $type OutOfBoundsError>]<D>(#[shrinkwrap(main_field)] $int, #[serde(skip)] std::marker::PhantomData<D>);
+
+        impl<D: crate::Domain<$int>> [<Union $type OutOfBoundsError>]<D> {
+            /// Returns the value that was attempted to be passed.
+            #[must_use]
+            pub fn value(&self) -> $int {
+                self.0
+            }
+
+            /// Returns whether the value lies within one of the ranges, but is excluded.
+            #[must_use]
+            pub fn is_excluded(&self) -> bool {
+                D::RANGES.iter().any(|range| range.contains(&self.0))
+            }
+
+            /// Returns the allowed sub-range that is nearest to the value. Ranges are split at excluded values, and ties are broken towards the smaller sub-range.
+            /// Returns `None` if the domain is empty.
+            #[must_use]
+            pub fn nearest_range(&self) -> Option<std::ops::RangeInclusive<$int>> {
+                let distance = |range: &std::ops::RangeInclusive<$int>| {
+                    if self.0 < *range.start() {
+                        range.start().abs_diff(self.0)
+                    } else if self.0 > *range.end() {
+                        self.0.abs_diff(*range.end())
+                    } else {
+                        0
+                    }
+                };
+                [<Union $type>]::<D>::sub_ranges()
+                    .min_by_key(|range| (distance(range), *range.start()))
+            }
+        }
+
+        impl<D> Clone for [<Union $type OutOfBoundsError>]<D> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<D> Copy for [<Union $type OutOfBoundsError>]<D> {}
+
+        impl<D: crate::Domain<$int>> Debug for [<Union $type OutOfBoundsError>]<D> {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let mut debug = fmt.debug_struct(stringify!([<Union $type OutOfBoundsError>]));
+                debug.field("value", &self.value());
+                match self.nearest_range() {
+                    Some(nearest) => debug.field("nearest_range", &nearest),
+                    None => debug.field("nearest_range", &format_args!("empty domain")),
+                };
+                debug.finish()
+            }
+        }
+
+        impl<D: crate::Domain<$int>> std::fmt::Display for [<Union $type OutOfBoundsError>]<D> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if self.is_excluded() {
+                    write!(f, "value {} is excluded", self.value())?;
+                } else {
+                    write!(f, "value {} is out of bounds", self.value())?;
+                }
+                match self.nearest_range() {
+                    Some(nearest) => write!(f, ", nearest allowed range is {}..={}", nearest.start(), nearest.end()),
+                    None => write!(f, ", the domain is empty"),
+                }
+            }
+        }
+
+        impl<D: crate::Domain<$int>> std::error::Error for [<Union $type OutOfBoundsError>]<D> {}
+
+        #[derive(Shrinkwrap, Serialize, Deserialize)]
+        #[serde(bound = "")]
+        #[doc="An `" $int "` element that is forced to be within the domain `D`, which may consist of several ranges and exclude single values."]
+        pub struct [<Union $type>]<D>(Result<$int, [<Union $type OutOfBoundsError>]<D>>);
+
+        impl<D: crate::Domain<$int>> [<Union $type>]<D> {
+            /// Returns the numeric value stored in the struct, but overrides the bounds check.
+            #[must_use]
+            pub fn unchecked(&self) -> $int {
+                match &self.0 {
+                    Ok(val) => *val,
+                    Err(err) => err.value(),
+                }
+            }
+
+            /// Function that returns whether a value is within the domain.
+            pub fn is_in_bounds(val: &impl PartialOrd<$int>) -> bool {
+                D::RANGES.iter().any(|range| *val >= *range.start() && *val <= *range.end())
+                    && !D::EXCLUDED.iter().any(|excluded| *val == *excluded)
+            }
+
+            /// Returns the contiguous sub-ranges of the domain, which are the ranges split at the excluded values.
+            fn sub_ranges() -> impl Iterator<Item = std::ops::RangeInclusive<$int>> {
+                let mut excluded = D::EXCLUDED.to_vec();
+                excluded.sort_unstable();
+                D::RANGES.iter().flat_map(move |range| {
+                    let mut sub_ranges = Vec::new();
+                    let mut start = Some(*range.start());
+                    for &value in excluded.iter().filter(|value| range.contains(*value)) {
+                        match start {
+                            Some(first) if first < value => sub_ranges.push(first..=value - 1),
+                            _ => {}
+                        }
+                        start = value.checked_add(1);
+                    }
+                    match start {
+                        Some(first) if first <= *range.end() => sub_ranges.push(first..=*range.end()),
+                        _ => {}
+                    }
+                    sub_ranges
+                })
+            }
+
+            /// Returns the value as a bounded type over all values of the backing integer, so that it can be compared.
+            fn as_unbounded(&self) -> Option<$type<{ <$int>::MIN }, { <$int>::MAX }>> {
+                self.0.as_ref().ok().map(|val| $type::from(*val))
+            }
+        }
+
+        impl<D> Clone for [<Union $type>]<D> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<D> Copy for [<Union $type>]<D> {}
+
+        impl<D: crate::Domain<$int>> Debug for [<Union $type>]<D> {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                fmt.debug_tuple(stringify!([<Union $type>])).field(&self.0).finish()
+            }
+        }
+
+        impl<D: crate::Domain<$int>> std::fmt::Display for [<Union $type>]<D> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match &self.0 {
+                    Ok(val) => write!(f, "{}", val),
+                    Err(err) => write!(f, "{:?}", err),
+                }
+            }
+        }
+
+        impl<D: crate::Domain<$int>> FromStr for [<Union $type>]<D> {
+            type Err = <$bound as FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <$bound>::from_str(s).map(Self::from)
+            }
+        }
+
+        impl<D: crate::Domain<$int>, E: crate::Domain<$int>> PartialEq<[<Union $type>]<E>> for [<Union $type>]<D> {
+            // will throw false if either side is out of bounds
+            fn eq(&self, other: &[<Union $type>]<E>) -> bool {
+                match (self.as_unbounded(), other.as_unbounded()) {
+                    (Some(self_val), Some(other_val)) => self_val == other_val,
+                    _ => false,
+                }
+            }
+        }
+
+        impl<D: crate::Domain<$int>, E: crate::Domain<$int>> PartialOrd<[<Union $type>]<E>> for [<Union $type>]<D> {
+            // will throw false if either side is out of bounds
+            fn partial_cmp(&self, other: &[<Union $type>]<E>) -> Option<Ordering> {
+                self.as_unbounded()?.partial_cmp(&other.as_unbounded()?)
+            }
+        }
+
+        impl<D: crate::Domain<$int>, const MIN: $bound, const MAX: $bound> PartialEq<$type<MIN, MAX>> for [<Union $type>]<D> {
+            // will throw false if either side is out of bounds
+            fn eq(&self, other: &$type<MIN, MAX>) -> bool {
+                self.as_unbounded().map_or(false, |val| val == *other)
+            }
+        }
+
+        impl<D: crate::Domain<$int>, const MIN: $bound, const MAX: $bound> PartialOrd<$type<MIN, MAX>> for [<Union $type>]<D> {
+            // will throw false if either side is out of bounds
+            fn partial_cmp(&self, other: &$type<MIN, MAX>) -> Option<Ordering> {
+                self.as_unbounded()?.partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<D: crate::Domain<$int>, const MIN: $bound, const MAX: $bound> PartialEq<[<Union $type>]<D>> for $type<MIN, MAX> {
+            fn eq(&self, other: &[<Union $type>]<D>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<D: crate::Domain<$int>, const MIN: $bound, const MAX: $bound> PartialOrd<[<Union $type>]<D>> for $type<MIN, MAX> {
+            fn partial_cmp(&self, other: &[<Union $type>]<D>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+
+        derive_union_numeric_traits!([<Union $type>], [<Union $type OutOfBoundsError>], $type, $int; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        }
+    };
+}
+
+/// Derives the traits that define the relation of a `UnionBounded` type to other numeric types. Conversions and comparisons are deduced from the bounded type over all values of the backing integer.
+macro_rules! derive_union_numeric_traits {
+    ( $union: ident, $error: ident, $type: ident, $int:ty; $( $numeric:ty ),* ) => {
+        $(
+        impl<D: crate::Domain<$int>> From<$numeric> for $union<D> {
+            // numbers that can't be cast as $int are stored as $int::MIN or $int::MAX, whichever is closer
+            fn from(other: $numeric) -> Self {
+                let unbounded = $type::<{ <$int>::MIN }, { <$int>::MAX }>::from(other);
+                match *unbounded {
+                    Ok(val) if Self::is_in_bounds(&val) => Self(Ok(val)),
+                    _ => Self(Err($error(unbounded.unchecked(), std::marker::PhantomData))),
+                }
+            }
+        }
+
+        impl<D: crate::Domain<$int>> PartialEq<$numeric> for $union<D> {
+            // will throw false if self is out of bounds
+            fn eq(&self, other: &$numeric) -> bool {
+                self.as_unbounded().map_or(false, |val| val == *other)
+            }
+        }
+
+        impl<D: crate::Domain<$int>> PartialOrd<$numeric> for $union<D> {
+            // will throw false if self is out of bounds
+            fn partial_cmp(&self, other: &$numeric) -> Option<Ordering> {
+                self.as_unbounded()?.partial_cmp(other)
+            }
+        }
+
+        /// Inferred through symmetry.
+        impl<D: crate::Domain<$int>> PartialEq<$union<D>> for $numeric {
+            fn eq(&self, other: &$union<D>) -> bool {
+                other == self
+            }
+        }
+
+        /// Inferred through assymetry.
+        impl<D: crate::Domain<$int>> PartialOrd<$union<D>> for $numeric {
+            fn partial_cmp(&self, other: &$union<D>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )*
+    };
+}